@@ -0,0 +1,647 @@
+/// Checks if a year is a leap year, according to the gregorian calendar.
+///
+/// ### Parameters
+/// * `year` - The year to check.
+///
+/// ### Return
+/// * `true` - `year` is a leap year.
+/// * `false` - `year` is not a leap year.
+///
+/// ### Panic
+/// `year` is 0.
+pub fn is_leap_year(year: u32) -> bool {
+	assert!(year > 0, "Invalid year");
+	match (year % 4 == 0, year % 100 == 0, year % 400 == 0) {
+		(true, true, true) => true,
+		(true, true, false) => false,
+		(true, false, _) => true,
+		(false, _, _) => false,
+	}
+}
+
+#[test]
+#[should_panic(expected = "Invalid year")]
+fn is_leap_year_0() {
+	is_leap_year(0);
+}
+
+#[test]
+fn is_leap_year_1() {
+	assert!(!is_leap_year(1));
+}
+
+#[test]
+fn is_leap_year_2() {
+	assert!(!is_leap_year(2));
+}
+
+#[test]
+fn is_leap_year_3() {
+	assert!(!is_leap_year(3));
+}
+
+#[test]
+fn is_leap_year_4() {
+	assert!(is_leap_year(4));
+}
+
+#[test]
+fn is_leap_year_5() {
+	assert!(!is_leap_year(5));
+}
+
+#[test]
+fn is_leap_year_6() {
+	assert!(!is_leap_year(6));
+}
+
+#[test]
+fn is_leap_year_7() {
+	assert!(!is_leap_year(7));
+}
+
+#[test]
+fn is_leap_year_8() {
+	assert!(is_leap_year(8));
+}
+
+#[test]
+fn is_leap_year_100() {
+	assert!(!is_leap_year(100));
+}
+
+#[test]
+fn is_leap_year_200() {
+	assert!(!is_leap_year(200));
+}
+
+#[test]
+fn is_leap_year_300() {
+	assert!(!is_leap_year(300));
+}
+
+#[test]
+fn is_leap_year_400() {
+	assert!(is_leap_year(400));
+}
+
+#[test]
+fn is_leap_year_500() {
+	assert!(!is_leap_year(500));
+}
+
+#[test]
+fn is_leap_year_600() {
+	assert!(!is_leap_year(600));
+}
+
+#[test]
+fn is_leap_year_700() {
+	assert!(!is_leap_year(700));
+}
+
+#[test]
+fn is_leap_year_800() {
+	assert!(is_leap_year(800));
+}
+
+/// Gets the number of days of a month.
+///
+/// ### Parameters
+/// * `year` - The year of the month.
+/// * `month` - The month, from 1 (January) to 12 (December).
+///
+/// ### Return
+/// The number of days of `month` in `year`.
+///
+/// ### Panic
+/// * `year` is 0.
+/// * `month` is not between 1 and 12.
+pub fn num_days_in_month(year: u32, month: u32) -> u32 {
+	assert!(year > 0, "Invalid year");
+	assert!(month > 0 && month < 13, "Invalid month");
+	if month > 7 {
+		if month % 2 == 0 {
+			return 31;
+		}
+		return 30;
+	}
+	if month == 2 {
+		if is_leap_year(year) {
+			return 29;
+		}
+		return 28;
+	}
+	if month % 2 == 0 {
+		return 30;
+	}
+	return 31;
+}
+
+#[test]
+fn num_days_in_month_00() {
+	assert!(num_days_in_month(1, 1) == 31);
+}
+
+#[test]
+fn num_days_in_month_01() {
+	assert!(num_days_in_month(1, 2) == 28);
+}
+
+#[test]
+fn num_days_in_month_02() {
+	assert!(num_days_in_month(1, 3) == 31);
+}
+
+#[test]
+fn num_days_in_month_03() {
+	assert!(num_days_in_month(1, 4) == 30);
+}
+
+#[test]
+fn num_days_in_month_04() {
+	assert!(num_days_in_month(1, 5) == 31);
+}
+
+#[test]
+fn num_days_in_month_05() {
+	assert!(num_days_in_month(1, 6) == 30);
+}
+
+#[test]
+fn num_days_in_month_06() {
+	assert!(num_days_in_month(1, 7) == 31);
+}
+
+#[test]
+fn num_days_in_month_07() {
+	assert!(num_days_in_month(1, 8) == 31);
+}
+
+#[test]
+fn num_days_in_month_08() {
+	assert!(num_days_in_month(1, 9) == 30);
+}
+
+#[test]
+fn num_days_in_month_09() {
+	assert!(num_days_in_month(1, 10) == 31);
+}
+
+#[test]
+fn num_days_in_month_10() {
+	assert!(num_days_in_month(1, 11) == 30);
+}
+
+#[test]
+fn num_days_in_month_11() {
+	assert!(num_days_in_month(1, 12) == 31);
+}
+
+#[test]
+fn num_days_in_month_12() {
+	assert!(num_days_in_month(4, 1) == 31);
+}
+
+#[test]
+fn num_days_in_month_13() {
+	assert!(num_days_in_month(4, 2) == 29);
+}
+
+#[test]
+fn num_days_in_month_14() {
+	assert!(num_days_in_month(4, 3) == 31);
+}
+
+#[test]
+fn num_days_in_month_15() {
+	assert!(num_days_in_month(4, 4) == 30);
+}
+
+#[test]
+fn num_days_in_month_16() {
+	assert!(num_days_in_month(4, 5) == 31);
+}
+
+#[test]
+fn num_days_in_month_17() {
+	assert!(num_days_in_month(4, 6) == 30);
+}
+
+#[test]
+fn num_days_in_month_18() {
+	assert!(num_days_in_month(4, 7) == 31);
+}
+
+#[test]
+fn num_days_in_month_19() {
+	assert!(num_days_in_month(4, 8) == 31);
+}
+
+#[test]
+fn num_days_in_month_20() {
+	assert!(num_days_in_month(4, 9) == 30);
+}
+
+#[test]
+fn num_days_in_month_21() {
+	assert!(num_days_in_month(4, 10) == 31);
+}
+
+#[test]
+fn num_days_in_month_22() {
+	assert!(num_days_in_month(4, 11) == 30);
+}
+
+#[test]
+fn num_days_in_month_23() {
+	assert!(num_days_in_month(4, 12) == 31);
+}
+
+#[test]
+fn num_days_in_month_24() {
+	assert!(num_days_in_month(100, 1) == 31);
+}
+
+#[test]
+fn num_days_in_month_25() {
+	assert!(num_days_in_month(100, 2) == 28);
+}
+
+#[test]
+fn num_days_in_month_26() {
+	assert!(num_days_in_month(100, 3) == 31);
+}
+
+#[test]
+fn num_days_in_month_27() {
+	assert!(num_days_in_month(100, 4) == 30);
+}
+
+#[test]
+fn num_days_in_month_28() {
+	assert!(num_days_in_month(100, 5) == 31);
+}
+
+#[test]
+fn num_days_in_month_29() {
+	assert!(num_days_in_month(100, 6) == 30);
+}
+
+#[test]
+fn num_days_in_month_30() {
+	assert!(num_days_in_month(100, 7) == 31);
+}
+
+#[test]
+fn num_days_in_month_31() {
+	assert!(num_days_in_month(100, 8) == 31);
+}
+
+#[test]
+fn num_days_in_month_32() {
+	assert!(num_days_in_month(100, 9) == 30);
+}
+
+#[test]
+fn num_days_in_month_33() {
+	assert!(num_days_in_month(100, 10) == 31);
+}
+
+#[test]
+fn num_days_in_month_34() {
+	assert!(num_days_in_month(100, 11) == 30);
+}
+
+#[test]
+fn num_days_in_month_35() {
+	assert!(num_days_in_month(100, 12) == 31);
+}
+
+#[test]
+fn num_days_in_month_36() {
+	assert!(num_days_in_month(400, 1) == 31);
+}
+
+#[test]
+fn num_days_in_month_37() {
+	assert!(num_days_in_month(400, 2) == 29);
+}
+
+#[test]
+fn num_days_in_month_38() {
+	assert!(num_days_in_month(400, 3) == 31);
+}
+
+#[test]
+fn num_days_in_month_39() {
+	assert!(num_days_in_month(400, 4) == 30);
+}
+
+#[test]
+fn num_days_in_month_40() {
+	assert!(num_days_in_month(400, 5) == 31);
+}
+
+#[test]
+fn num_days_in_month_41() {
+	assert!(num_days_in_month(400, 6) == 30);
+}
+
+#[test]
+fn num_days_in_month_42() {
+	assert!(num_days_in_month(400, 7) == 31);
+}
+
+#[test]
+fn num_days_in_month_43() {
+	assert!(num_days_in_month(400, 8) == 31);
+}
+
+#[test]
+fn num_days_in_month_44() {
+	assert!(num_days_in_month(400, 9) == 30);
+}
+
+#[test]
+fn num_days_in_month_45() {
+	assert!(num_days_in_month(400, 10) == 31);
+}
+
+#[test]
+fn num_days_in_month_46() {
+	assert!(num_days_in_month(400, 11) == 30);
+}
+
+#[test]
+fn num_days_in_month_47() {
+	assert!(num_days_in_month(400, 12) == 31);
+}
+
+#[test]
+#[should_panic(expected = "Invalid year")]
+fn num_days_in_month_48() {
+	num_days_in_month(0, 1);
+}
+
+#[test]
+#[should_panic(expected = "Invalid month")]
+fn num_days_in_month_49() {
+	num_days_in_month(1, 0);
+}
+
+#[test]
+#[should_panic(expected = "Invalid month")]
+fn num_days_in_month_50() {
+	num_days_in_month(1, 13);
+}
+
+/// Gets the english name of a month.
+///
+/// ### Parameters
+/// * `month` - The month, from 1 (January) to 12 (December).
+///
+/// ### Return
+/// The name of `month`.
+///
+/// ### Panic
+/// `month` is not between 1 and 12.
+pub fn month_name(month: u32) -> &'static str {
+	match month {
+		1 => "Januaray",
+		2 => "February",
+		3 => "March",
+		4 => "April",
+		5 => "May",
+		6 => "June",
+		7 => "July",
+		8 => "August",
+		9 => "September",
+		10 => "October",
+		11 => "November",
+		12 => "December",
+		_ => panic!("Invalid month"),
+	}
+}
+
+#[test]
+fn month_name_00() {
+	assert_eq!(month_name(1), "Januaray");
+}
+
+#[test]
+fn month_name_01() {
+	assert_eq!(month_name(2), "February");
+}
+
+#[test]
+fn month_name_02() {
+	assert_eq!(month_name(3), "March");
+}
+
+#[test]
+fn month_name_03() {
+	assert_eq!(month_name(4), "April");
+}
+
+#[test]
+fn month_name_04() {
+	assert_eq!(month_name(5), "May");
+}
+
+#[test]
+fn month_name_05() {
+	assert_eq!(month_name(6), "June");
+}
+
+#[test]
+fn month_name_06() {
+	assert_eq!(month_name(7), "July");
+}
+
+#[test]
+fn month_name_07() {
+	assert_eq!(month_name(8), "August");
+}
+
+#[test]
+fn month_name_08() {
+	assert_eq!(month_name(9), "September");
+}
+
+#[test]
+fn month_name_09() {
+	assert_eq!(month_name(10), "October");
+}
+
+#[test]
+fn month_name_10() {
+	assert_eq!(month_name(11), "November");
+}
+
+#[test]
+fn month_name_11() {
+	assert_eq!(month_name(12), "December");
+}
+
+#[test]
+#[should_panic(expected = "Invalid month")]
+fn month_name_12() {
+	month_name(0);
+}
+
+#[test]
+#[should_panic(expected = "Invalid month")]
+fn month_name_13() {
+	month_name(13);
+}
+
+#[test]
+#[should_panic(expected = "Invalid month")]
+fn month_name_14() {
+	month_name(u32::MAX);
+}
+
+/// Counts the leap years of a range of years.
+///
+/// ### Parameters
+/// * `start` - The first year of the range, inclusive.
+/// * `end` - The last year of the range, inclusive.
+///
+/// ### Return
+/// The number of leap years between `start` and `end`.
+///
+/// ### Panic
+/// `start` is 0.
+///
+/// ### Example
+/// ```
+/// use ex05::leap_years_between;
+///
+/// assert_eq!(leap_years_between(1, 8), 2);
+/// ```
+pub fn leap_years_between(start: u32, end: u32) -> u32 {
+	assert!(start > 0, "Invalid year");
+	(start..=end).filter(|year: &u32| is_leap_year(*year)).count() as u32
+}
+
+#[test]
+fn leap_years_between_00() {
+	assert_eq!(leap_years_between(1, 8), 2);
+}
+
+#[test]
+fn leap_years_between_01() {
+	assert_eq!(leap_years_between(1, 3), 0);
+}
+
+#[test]
+fn leap_years_between_02() {
+	assert_eq!(leap_years_between(4, 4), 1);
+}
+
+#[test]
+fn leap_years_between_03() {
+	assert_eq!(leap_years_between(8, 4), 0);
+}
+
+#[test]
+fn leap_years_between_04() {
+	assert_eq!(leap_years_between(1, 400), 97);
+}
+
+#[test]
+fn leap_years_between_05() {
+	assert_eq!(leap_years_between(1900, 2023), 30);
+}
+
+#[test]
+#[should_panic(expected = "Invalid year")]
+fn leap_years_between_06() {
+	leap_years_between(0, 8);
+}
+
+/// Lists every Friday the 13th of a range of years.
+///
+/// ### Parameters
+/// * `start_year` - The first year of the range, inclusive.
+/// * `end_year` - The last year of the range, inclusive.
+///
+/// ### Return
+/// The `(year, month)` pairs of every month of the range whose 13th day is a Friday, in order.
+///
+/// ### Panic
+/// `start_year` is 0.
+///
+/// ### Example
+/// ```
+/// use ex05::friday_the_13ths;
+///
+/// assert_eq!(friday_the_13ths(2023, 2023), [(2023, 1), (2023, 10)]);
+/// ```
+pub fn friday_the_13ths(start_year: u32, end_year: u32) -> Vec<(u32, u32)> {
+	assert!(start_year > 0, "Invalid year");
+
+	// The Gregorian calendar repeats itself every 400 years (146097 days, a multiple of 7),
+	// so the first day of each 400-year cycle is the same weekday as the first day of year 1.
+	let mut total: u32 = 0;
+	let mut fridays: Vec<(u32, u32)> = Vec::new();
+
+	for year in start_year - (start_year - 1) % 400..=end_year {
+		for month in 1..=12 {
+			if year >= start_year && (total + 13) % 7 == 5 {
+				fridays.push((year, month));
+			}
+			total = (total + num_days_in_month(year, month)) % 7;
+		}
+	}
+	fridays
+}
+
+#[test]
+fn friday_the_13ths_00() {
+	assert_eq!(friday_the_13ths(1, 1), [(1, 4), (1, 7)]);
+}
+
+#[test]
+fn friday_the_13ths_01() {
+	assert_eq!(friday_the_13ths(2023, 2023), [(2023, 1), (2023, 10)]);
+}
+
+#[test]
+fn friday_the_13ths_02() {
+	assert_eq!(friday_the_13ths(2015, 2023).len(), 16);
+}
+
+#[test]
+fn friday_the_13ths_03() {
+	assert_eq!(friday_the_13ths(1, 2023).len(), 3480);
+}
+
+#[test]
+fn friday_the_13ths_04() {
+	assert_eq!(friday_the_13ths(2024, 2023), []);
+}
+
+#[test]
+#[should_panic(expected = "Invalid year")]
+fn friday_the_13ths_05() {
+	friday_the_13ths(0, 2023);
+}
+
+#[test]
+fn friday_the_13ths_06() {
+	// The Gregorian calendar repeats itself every 400 years.
+	assert_eq!(friday_the_13ths(12_000_000, 12_000_000), [(12_000_000, 10)]);
+	assert_eq!(friday_the_13ths(2000, 2000), [(2000, 10)]);
+}
+
+#[test]
+fn friday_the_13ths_07() {
+	let months = |fridays: Vec<(u32, u32)>| -> Vec<u32> {
+		fridays.into_iter().map(|(_, month): (u32, u32)| month).collect()
+	};
+
+	assert_eq!(months(friday_the_13ths(u32::MAX, u32::MAX)), months(friday_the_13ths(2095, 2095)));
+	assert_eq!(friday_the_13ths(401, 2023), friday_the_13ths(1, 2023)[688..]);
+}
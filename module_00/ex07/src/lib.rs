@@ -87,7 +87,57 @@ fn strstr(haystack: &[u8], needle: &[u8], i: &mut usize) -> bool {
 	return false;
 }
 
+/// Compares a string against a pattern of the same length, position by position.
+/// A `?` in the pattern matches any single character.
+///
+/// ### Parameters
+/// * `s` - The string to compare.
+/// * `pattern` - The pattern to compare against.
+///
+/// ### Returns
+/// * `true` - `s` matches `pattern`.
+/// * `false` - `s` does not match `pattern`.
+fn wildcmp(s: &[u8], pattern: &[u8]) -> bool {
+	s.len() == pattern.len()
+		&& s.iter().zip(pattern).all(|(&c, &p): (&u8, &u8)| p == b'?' || c == p)
+}
+
+/// Searches for the first occurence of a pattern in a string.
+/// A `?` in the pattern matches any single character.
+///
+/// ### Parameters
+/// * `haystack` - The string to search in.
+/// * `needle` - The pattern to search for.
+/// * `i` - The index of the first character of the first occurence of the needle in the haystack.
+///
+/// ### Returns
+/// * `true` - `needle` was found in `haystack`.
+/// * `false` - `needle` was not found in `haystack`.
+fn wildstr(haystack: &[u8], needle: &[u8], i: &mut usize) -> bool {
+	let mut j: usize = 0;
+
+	if !strchr(needle, b'?', &mut j) {
+		return strstr(haystack, needle, i);
+	}
+
+	if needle.len() > haystack.len() {
+		return false;
+	}
+
+	j = 0;
+	while j <= haystack.len() - needle.len() {
+		if wildcmp(&haystack[j..j + needle.len()], needle) {
+			*i = j;
+			return true;
+		}
+		j += 1;
+	}
+	false
+}
+
 /// Checks whether a string matches a pattern.
+/// In the pattern, `*` matches any sequence of characters (including an empty one),
+/// and `?` matches exactly one character.
 ///
 /// ### Parameters
 /// * `query` - The string to check.
@@ -102,47 +152,382 @@ fn strstr(haystack: &[u8], needle: &[u8], i: &mut usize) -> bool {
 /// use ex07::strpcmp;
 ///
 /// assert_eq!(strpcmp(b"Hello World!", b"He*o*rld*"), true);
+/// assert_eq!(strpcmp(b"Hello World!", b"He?lo*W?rld?"), true);
 /// ```
 pub fn strpcmp(query: &[u8], pattern: &[u8]) -> bool {
+	strpcmp_spans(query, pattern, &mut Vec::new())
+}
+
+/// Checks whether a string matches a pattern, and retrieves where each part of the pattern
+/// that is not a `*` matched in the string.
+///
+/// ### Parameters
+/// * `query` - The string to check.
+/// * `pattern` - The pattern to check against.
+/// * `spans` - The `(start, end)` ranges of `query` matched by each non-empty part of `pattern`
+///   that is delimited by `*`, in order. It is cleared beforehand,
+///   and left empty if the string does not match the pattern.
+///
+/// ### Returns
+/// * `true` - The string matches the pattern.
+/// * `false` - The string does not match the pattern.
+///
+/// ### Example
+/// ```
+/// use ex07::strpcmp_spans;
+///
+/// let mut spans: Vec<(usize, usize)> = Vec::new();
+///
+/// assert_eq!(strpcmp_spans(b"axbyc", b"a*b*c", &mut spans), true);
+/// assert_eq!(spans, [(0, 1), (2, 3), (4, 5)]);
+/// ```
+pub fn strpcmp_spans(query: &[u8], pattern: &[u8], spans: &mut Vec<(usize, usize)>) -> bool {
 	let mut i0: usize = 0;
+	let mut i1: usize = 0;
 
-	if !strrchr(pattern, b'*', &mut i0) && query[..] != pattern[..] {
-		return false;
+	spans.clear();
+	if !strchr(pattern, b'*', &mut i0) {
+		if !wildcmp(query, pattern) {
+			return false;
+		}
+		if !pattern.is_empty() {
+			spans.push((0, query.len()));
+		}
+		return true;
 	}
-	i0 += 1;
-	if i0 < pattern.len() && query[query.len() - (pattern.len() - i0)..] != pattern[i0..] {
+	strrchr(pattern, b'*', &mut i1);
+
+	let prefix: &[u8] = &pattern[..i0];
+	let suffix: &[u8] = &pattern[i1 + 1..];
+
+	if query.len() < prefix.len() + suffix.len()
+		|| !wildcmp(&query[..prefix.len()], prefix)
+		|| !wildcmp(&query[query.len() - suffix.len()..], suffix)
+	{
 		return false;
 	}
-	i0 = 0;
-	strchr(pattern, b'*', &mut i0);
-	if query[..i0] != pattern[..i0] {
-		return false;
+	if !prefix.is_empty() {
+		spans.push((0, prefix.len()));
 	}
 
-	let mut i1: usize = 0;
-	let mut i2: usize;
+	let mut i2: usize = prefix.len();
 	let mut i3: usize;
 
-	while i0 < pattern.len() {
-		while i0 < pattern.len() && pattern[i0] == b'*' {
-			i0 += 1;
-		}
-		i2 = i0;
-		while i2 < pattern.len() && pattern[i2] != b'*' {
-			i2 += 1;
+	for segment in pattern[i0..i1].split(|&c: &u8| c == b'*') {
+		if segment.is_empty() {
+			continue;
 		}
-		i3 = i1;
-		if !strstr(&query[i1..], &pattern[i0..i2], &mut i1) {
+		i3 = 0;
+		if !wildstr(&query[i2..query.len() - suffix.len()], segment, &mut i3) {
+			spans.clear();
 			return false;
 		}
-		i1 += i3;
-		i1 += i2 - i0;
-		i0 = i2;
+		i2 += i3;
+		spans.push((i2, i2 + segment.len()));
+		i2 += segment.len();
+	}
+	if !suffix.is_empty() {
+		spans.push((query.len() - suffix.len(), query.len()));
 	}
 
 	true
 }
 
+/// Checks whether a string matches a pattern, ignoring ASCII case.
+/// Non-ASCII bytes are compared verbatim.
+///
+/// ### Parameters
+/// * `query` - The string to check.
+/// * `pattern` - The pattern to check against.
+///
+/// ### Returns
+/// * `true` - The string matches the pattern.
+/// * `false` - The string does not match the pattern.
+///
+/// ### Example
+/// ```
+/// use ex07::strpcmp_ci;
+///
+/// assert_eq!(strpcmp_ci(b"Hello World!", b"he*O*RLD*"), true);
+/// ```
+pub fn strpcmp_ci(query: &[u8], pattern: &[u8]) -> bool {
+	strpcmp(&query.to_ascii_lowercase(), &pattern.to_ascii_lowercase())
+}
+
+/// Removes a prefix from a string.
+///
+/// ### Parameters
+/// * `s` - The string to remove the prefix from.
+/// * `prefix` - The prefix to remove.
+///
+/// ### Returns
+/// * `Some(rest)` - `s` starts with `prefix`, and `rest` is what follows it.
+/// * `None` - `s` does not start with `prefix`.
+///
+/// ### Example
+/// ```
+/// use ex07::strip_prefix;
+///
+/// assert_eq!(strip_prefix(b"foobar", b"foo"), Some(&b"bar"[..]));
+/// assert_eq!(strip_prefix(b"foobar", b"bar"), None);
+/// ```
+pub fn strip_prefix<'a>(s: &'a [u8], prefix: &[u8]) -> Option<&'a [u8]> {
+	if prefix.len() <= s.len() && s[..prefix.len()] == prefix[..] {
+		Some(&s[prefix.len()..])
+	} else {
+		None
+	}
+}
+
+/// Removes a suffix from a string.
+///
+/// ### Parameters
+/// * `s` - The string to remove the suffix from.
+/// * `suffix` - The suffix to remove.
+///
+/// ### Returns
+/// * `Some(rest)` - `s` ends with `suffix`, and `rest` is what precedes it.
+/// * `None` - `s` does not end with `suffix`.
+///
+/// ### Example
+/// ```
+/// use ex07::strip_suffix;
+///
+/// assert_eq!(strip_suffix(b"foobar", b"bar"), Some(&b"foo"[..]));
+/// assert_eq!(strip_suffix(b"foobar", b"foo"), None);
+/// ```
+pub fn strip_suffix<'a>(s: &'a [u8], suffix: &[u8]) -> Option<&'a [u8]> {
+	if suffix.len() <= s.len() && s[s.len() - suffix.len()..] == suffix[..] {
+		Some(&s[..s.len() - suffix.len()])
+	} else {
+		None
+	}
+}
+
+/// Removes the leading and trailing whitespaces of a string.
+/// The whitespaces are the space and the `\t`, `\n`, `\x0b`, `\x0c` and `\r` characters.
+///
+/// ### Parameters
+/// * `s` - The string to trim.
+///
+/// ### Returns
+/// The part of `s` that is between its leading and trailing whitespaces.
+///
+/// ### Example
+/// ```
+/// use ex07::trim_bytes;
+///
+/// assert_eq!(trim_bytes(b"  hi \n"), b"hi");
+/// ```
+pub fn trim_bytes(s: &[u8]) -> &[u8] {
+	let is_space = |c: &u8| -> bool { *c == b' ' || (b'\t'..=b'\r').contains(c) };
+	let mut start: usize = 0;
+	let mut end: usize = s.len();
+
+	while start < end && is_space(&s[start]) {
+		start += 1;
+	}
+	while start < end && is_space(&s[end - 1]) {
+		end -= 1;
+	}
+	&s[start..end]
+}
+
+/// An iterator over the subslices of a string separated by a given character.
+pub struct ByteSplit<'a> {
+	/// The part of the string that has not been split yet.
+	rest: &'a [u8],
+
+	/// The character that separates the subslices.
+	sep: u8,
+
+	/// A boolean that indicates if the end of the iterator has been reached.
+	is_end_reached: bool,
+}
+
+/// Splits a string on every occurence of a character.
+/// As with `str::split`, empty subslices are yielded between consecutive separators,
+/// and at the beginning or the end of the string.
+///
+/// ### Parameters
+/// * `haystack` - The string to split.
+/// * `sep` - The character to split on.
+///
+/// ### Returns
+/// An iterator over the subslices of `haystack` separated by `sep`.
+///
+/// ### Example
+/// ```
+/// use ex07::split_bytes;
+///
+/// let mut split = split_bytes(b"a,b,,c", b',');
+///
+/// assert_eq!(split.next(), Some(&b"a"[..]));
+/// assert_eq!(split.next(), Some(&b"b"[..]));
+/// assert_eq!(split.next(), Some(&b""[..]));
+/// assert_eq!(split.next(), Some(&b"c"[..]));
+/// assert_eq!(split.next(), None);
+/// ```
+pub fn split_bytes(haystack: &[u8], sep: u8) -> ByteSplit<'_> {
+	ByteSplit { rest: haystack, sep, is_end_reached: false }
+}
+
+impl<'a> Iterator for ByteSplit<'a> {
+	type Item = &'a [u8];
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.is_end_reached {
+			return None;
+		}
+
+		let mut i: usize = 0;
+
+		if strchr(self.rest, self.sep, &mut i) {
+			let subslice: &[u8] = &self.rest[..i];
+
+			self.rest = &self.rest[i + 1..];
+			Some(subslice)
+		} else {
+			self.is_end_reached = true;
+			Some(self.rest)
+		}
+	}
+}
+
+/// Checks whether two strings are anagrams of each other,
+/// that is, whether they contain the same characters the same number of times.
+///
+/// ### Parameters
+/// * `a` - The first string.
+/// * `b` - The second string.
+///
+/// ### Returns
+/// * `true` - `a` and `b` are anagrams of each other.
+/// * `false` - `a` and `b` are not anagrams of each other.
+///
+/// ### Example
+/// ```
+/// use ex07::is_anagram;
+///
+/// assert_eq!(is_anagram(b"listen", b"silent"), true);
+/// ```
+pub fn is_anagram(a: &[u8], b: &[u8]) -> bool {
+	if a.len() != b.len() {
+		return false;
+	}
+
+	let mut counts: [isize; 256] = [0; 256];
+
+	for i in 0..a.len() {
+		counts[a[i] as usize] += 1;
+		counts[b[i] as usize] -= 1;
+	}
+	counts.iter().all(|&count: &isize| count == 0)
+}
+
+/// Calculates the length of the longest common prefix of two strings.
+///
+/// ### Parameters
+/// * `a` - The first string.
+/// * `b` - The second string.
+///
+/// ### Returns
+/// The number of leading characters that `a` and `b` have in common.
+///
+/// ### Example
+/// ```
+/// use ex07::common_prefix_len;
+///
+/// assert_eq!(common_prefix_len(b"abcx", b"abcy"), 3);
+/// ```
+pub fn common_prefix_len(a: &[u8], b: &[u8]) -> usize {
+	let mut i: usize = 0;
+
+	while i < a.len() && i < b.len() && a[i] == b[i] {
+		i += 1;
+	}
+	i
+}
+
+/// Searches for the first occurence of a character in a string.
+///
+/// ### Parameters
+/// * `haystack` - The string to search in.
+/// * `needle` - The character to search for.
+///
+/// ### Returns
+/// * `Some(i)` - The byte index of the first occurence of `needle` in `haystack`.
+/// * `None` - `needle` was not found in `haystack`.
+///
+/// ### Example
+/// ```
+/// use ex07::find_char;
+///
+/// assert_eq!(find_char("Hello World!", 'o'), Some(4));
+/// assert_eq!(find_char("déjà vu", 'à'), Some(4));
+/// ```
+pub fn find_char(haystack: &str, needle: char) -> Option<usize> {
+	let mut i: usize = 0;
+
+	if needle.is_ascii() {
+		return if strchr(haystack.as_bytes(), needle as u8, &mut i) { Some(i) } else { None };
+	}
+	haystack.char_indices().find(|&(_, c): &(usize, char)| c == needle).map(|(i, _)| i)
+}
+
+/// Searches for the last occurence of a character in a string.
+///
+/// ### Parameters
+/// * `haystack` - The string to search in.
+/// * `needle` - The character to search for.
+///
+/// ### Returns
+/// * `Some(i)` - The byte index of the last occurence of `needle` in `haystack`.
+/// * `None` - `needle` was not found in `haystack`.
+///
+/// ### Example
+/// ```
+/// use ex07::rfind_char;
+///
+/// assert_eq!(rfind_char("Hello World!", 'o'), Some(7));
+/// assert_eq!(rfind_char("déjà vu", 'é'), Some(1));
+/// ```
+pub fn rfind_char(haystack: &str, needle: char) -> Option<usize> {
+	let mut i: usize = 0;
+
+	if needle.is_ascii() {
+		return if strrchr(haystack.as_bytes(), needle as u8, &mut i) { Some(i) } else { None };
+	}
+	haystack.char_indices().rev().find(|&(_, c): &(usize, char)| c == needle).map(|(i, _)| i)
+}
+
+/// Searches for the first occurence of a substring in a string.
+///
+/// ### Parameters
+/// * `haystack` - The string to search in.
+/// * `needle` - The string to search for.
+///
+/// ### Returns
+/// * `Some(i)` - The byte index of the first character of the first occurence
+///   of `needle` in `haystack`.
+/// * `None` - `needle` was not found in `haystack`.
+///
+/// ### Example
+/// ```
+/// use ex07::find_sub;
+///
+/// assert_eq!(find_sub("Hello World!", "World"), Some(6));
+/// assert_eq!(find_sub("déjà vu", "à v"), Some(4));
+/// ```
+pub fn find_sub(haystack: &str, needle: &str) -> Option<usize> {
+	let mut i: usize = 0;
+
+	if needle.is_ascii() {
+		return if strstr(haystack.as_bytes(), needle.as_bytes(), &mut i) { Some(i) } else { None };
+	}
+	haystack.char_indices().map(|(i, _)| i).find(|&i: &usize| haystack[i..].starts_with(needle))
+}
+
 #[cfg(test)]
 mod test {
 	use super::*;
@@ -318,6 +703,71 @@ mod test {
 		assert_eq!(i, 18);
 	}
 
+	#[test]
+	fn wildcmp_00() {
+		assert_eq!(wildcmp(b"", b""), true);
+	}
+
+	#[test]
+	fn wildcmp_01() {
+		assert_eq!(wildcmp(b"abc", b"a?c"), true);
+	}
+
+	#[test]
+	fn wildcmp_02() {
+		assert_eq!(wildcmp(b"ac", b"a?c"), false);
+	}
+
+	#[test]
+	fn wildcmp_03() {
+		assert_eq!(wildcmp(b"abd", b"a?c"), false);
+	}
+
+	#[test]
+	fn wildcmp_04() {
+		assert_eq!(wildcmp(b"xyz", b"???"), true);
+	}
+
+	#[test]
+	fn wildstr_00() {
+		let mut i: usize = 42;
+
+		assert_eq!(wildstr(b"Hello World!", b"W?r", &mut i), true);
+		assert_eq!(i, 6);
+	}
+
+	#[test]
+	fn wildstr_01() {
+		let mut i: usize = 42;
+
+		assert_eq!(wildstr(b"Hello World!", b"?", &mut i), true);
+		assert_eq!(i, 0);
+	}
+
+	#[test]
+	fn wildstr_02() {
+		let mut i: usize = 42;
+
+		assert_eq!(wildstr(b"ab", b"a??", &mut i), false);
+		assert_eq!(i, 42);
+	}
+
+	#[test]
+	fn wildstr_03() {
+		let mut i: usize = 42;
+
+		assert_eq!(wildstr(b"aaab", b"a?b", &mut i), true);
+		assert_eq!(i, 1);
+	}
+
+	#[test]
+	fn wildstr_04() {
+		let mut i: usize = 42;
+
+		assert_eq!(wildstr(b"Hello World!", b"World", &mut i), true);
+		assert_eq!(i, 6);
+	}
+
 	#[test]
 	fn strpcmp_00() {
 		assert_eq!(strpcmp(b"", b""), true);
@@ -617,4 +1067,501 @@ mod test {
 	fn strpcmp_59() {
 		assert_eq!(strpcmp(b"abcabcdabc", b"*abcd*abcd*"), false);
 	}
+
+	#[test]
+	fn strpcmp_60() {
+		assert_eq!(strpcmp(b"abc", b"a?c"), true);
+	}
+
+	#[test]
+	fn strpcmp_61() {
+		assert_eq!(strpcmp(b"ac", b"a?c"), false);
+	}
+
+	#[test]
+	fn strpcmp_62() {
+		assert_eq!(strpcmp(b"abcd", b"a?c"), false);
+	}
+
+	#[test]
+	fn strpcmp_63() {
+		assert_eq!(strpcmp(b"abc", b"?bc"), true);
+	}
+
+	#[test]
+	fn strpcmp_64() {
+		assert_eq!(strpcmp(b"bc", b"?bc"), false);
+	}
+
+	#[test]
+	fn strpcmp_65() {
+		assert_eq!(strpcmp(b"abc", b"ab?"), true);
+	}
+
+	#[test]
+	fn strpcmp_66() {
+		assert_eq!(strpcmp(b"ab", b"ab?"), false);
+	}
+
+	#[test]
+	fn strpcmp_67() {
+		assert_eq!(strpcmp(b"abc", b"???"), true);
+	}
+
+	#[test]
+	fn strpcmp_68() {
+		assert_eq!(strpcmp(b"ab", b"???"), false);
+	}
+
+	#[test]
+	fn strpcmp_69() {
+		assert_eq!(strpcmp(b"abcd", b"???"), false);
+	}
+
+	#[test]
+	fn strpcmp_70() {
+		assert_eq!(strpcmp(b"", b"?"), false);
+	}
+
+	#[test]
+	fn strpcmp_71() {
+		assert_eq!(strpcmp(b"?", b"?"), true);
+	}
+
+	#[test]
+	fn strpcmp_72() {
+		assert_eq!(strpcmp(b"abc", b"?*"), true);
+	}
+
+	#[test]
+	fn strpcmp_73() {
+		assert_eq!(strpcmp(b"", b"?*"), false);
+	}
+
+	#[test]
+	fn strpcmp_74() {
+		assert_eq!(strpcmp(b"abc", b"*?"), true);
+	}
+
+	#[test]
+	fn strpcmp_75() {
+		assert_eq!(strpcmp(b"", b"*?"), false);
+	}
+
+	#[test]
+	fn strpcmp_76() {
+		assert_eq!(strpcmp(b"abc", b"*???*"), true);
+	}
+
+	#[test]
+	fn strpcmp_77() {
+		assert_eq!(strpcmp(b"ab", b"*???*"), false);
+	}
+
+	#[test]
+	fn strpcmp_78() {
+		assert_eq!(strpcmp(b"Hello World!", b"He?lo*W?rld?"), true);
+	}
+
+	#[test]
+	fn strpcmp_79() {
+		assert_eq!(strpcmp(b"Hello World!", b"H?llo*?orld"), false);
+	}
+
+	#[test]
+	fn strpcmp_80() {
+		assert_eq!(strpcmp(b"axbyc", b"a*?y*c"), true);
+	}
+
+	#[test]
+	fn strpcmp_81() {
+		assert_eq!(strpcmp(b"axbyc", b"a*x?y*c"), true);
+	}
+
+	#[test]
+	fn strpcmp_82() {
+		assert_eq!(strpcmp(b"axbyc", b"a*y?y*c"), false);
+	}
+
+	#[test]
+	fn strpcmp_83() {
+		assert_eq!(strpcmp(b"abcabcdabc", b"abc*a?cd*abc"), true);
+	}
+
+	#[test]
+	fn strpcmp_84() {
+		assert_eq!(strpcmp(b"abc", b"abc*abc"), false);
+	}
+
+	#[test]
+	fn strpcmp_85() {
+		assert_eq!(strpcmp(b"abcab", b"ab*b*ab"), false);
+	}
+
+	#[test]
+	fn strpcmp_spans_00() {
+		let mut spans: Vec<(usize, usize)> = Vec::new();
+
+		assert_eq!(strpcmp_spans(b"axbyc", b"a*b*c", &mut spans), true);
+		assert_eq!(spans, [(0, 1), (2, 3), (4, 5)]);
+	}
+
+	#[test]
+	fn strpcmp_spans_01() {
+		let mut spans: Vec<(usize, usize)> = vec![(4, 2)];
+
+		assert_eq!(strpcmp_spans(b"axbyc", b"a*d*c", &mut spans), false);
+		assert!(spans.is_empty());
+	}
+
+	#[test]
+	fn strpcmp_spans_02() {
+		let mut spans: Vec<(usize, usize)> = vec![(4, 2)];
+
+		assert_eq!(strpcmp_spans(b"axbyc", b"a*b*d", &mut spans), false);
+		assert!(spans.is_empty());
+	}
+
+	#[test]
+	fn strpcmp_spans_03() {
+		let mut spans: Vec<(usize, usize)> = Vec::new();
+
+		assert_eq!(strpcmp_spans(b"abc", b"abc", &mut spans), true);
+		assert_eq!(spans, [(0, 3)]);
+	}
+
+	#[test]
+	fn strpcmp_spans_04() {
+		let mut spans: Vec<(usize, usize)> = Vec::new();
+
+		assert_eq!(strpcmp_spans(b"abc", b"***", &mut spans), true);
+		assert!(spans.is_empty());
+	}
+
+	#[test]
+	fn strpcmp_spans_05() {
+		let mut spans: Vec<(usize, usize)> = Vec::new();
+
+		assert_eq!(strpcmp_spans(b"Hello World!", b"*l?o**rld*", &mut spans), true);
+		assert_eq!(spans, [(2, 5), (8, 11)]);
+	}
+
+	#[test]
+	fn strpcmp_ci_00() {
+		assert_eq!(strpcmp_ci(b"", b""), true);
+	}
+
+	#[test]
+	fn strpcmp_ci_01() {
+		assert_eq!(strpcmp_ci(b"Hello", b"h*O"), true);
+		assert_eq!(strpcmp(b"Hello", b"h*O"), false);
+	}
+
+	#[test]
+	fn strpcmp_ci_02() {
+		assert_eq!(strpcmp_ci(b"README.md", b"readme.MD"), true);
+	}
+
+	#[test]
+	fn strpcmp_ci_03() {
+		assert_eq!(strpcmp_ci(b"Makefile", b"*FILE"), true);
+	}
+
+	#[test]
+	fn strpcmp_ci_04() {
+		assert_eq!(strpcmp_ci(b"Makefile", b"*FILES"), false);
+	}
+
+	#[test]
+	fn strpcmp_ci_05() {
+		assert_eq!(strpcmp_ci("DÉJÀ VU".as_bytes(), "déjà*".as_bytes()), false);
+		assert_eq!(strpcmp_ci("DÉJÀ VU".as_bytes(), "dÉjÀ*".as_bytes()), true);
+	}
+
+	#[test]
+	fn strip_prefix_00() {
+		assert_eq!(strip_prefix(b"", b""), Some(&b""[..]));
+	}
+
+	#[test]
+	fn strip_prefix_01() {
+		assert_eq!(strip_prefix(b"foobar", b""), Some(&b"foobar"[..]));
+	}
+
+	#[test]
+	fn strip_prefix_02() {
+		assert_eq!(strip_prefix(b"foobar", b"foo"), Some(&b"bar"[..]));
+	}
+
+	#[test]
+	fn strip_prefix_03() {
+		assert_eq!(strip_prefix(b"foobar", b"foobar"), Some(&b""[..]));
+	}
+
+	#[test]
+	fn strip_prefix_04() {
+		assert_eq!(strip_prefix(b"foobar", b"bar"), None);
+	}
+
+	#[test]
+	fn strip_prefix_05() {
+		assert_eq!(strip_prefix(b"foo", b"foobar"), None);
+	}
+
+	#[test]
+	fn strip_suffix_00() {
+		assert_eq!(strip_suffix(b"", b""), Some(&b""[..]));
+	}
+
+	#[test]
+	fn strip_suffix_01() {
+		assert_eq!(strip_suffix(b"foobar", b""), Some(&b"foobar"[..]));
+	}
+
+	#[test]
+	fn strip_suffix_02() {
+		assert_eq!(strip_suffix(b"foobar", b"bar"), Some(&b"foo"[..]));
+	}
+
+	#[test]
+	fn strip_suffix_03() {
+		assert_eq!(strip_suffix(b"foobar", b"foobar"), Some(&b""[..]));
+	}
+
+	#[test]
+	fn strip_suffix_04() {
+		assert_eq!(strip_suffix(b"foobar", b"foo"), None);
+	}
+
+	#[test]
+	fn strip_suffix_05() {
+		assert_eq!(strip_suffix(b"bar", b"foobar"), None);
+	}
+
+	#[test]
+	fn trim_bytes_00() {
+		assert_eq!(trim_bytes(b""), b"");
+	}
+
+	#[test]
+	fn trim_bytes_01() {
+		assert_eq!(trim_bytes(b"  hi \n"), b"hi");
+	}
+
+	#[test]
+	fn trim_bytes_02() {
+		assert_eq!(trim_bytes(b" \t\n\x0b\x0c\r"), b"");
+	}
+
+	#[test]
+	fn trim_bytes_03() {
+		assert_eq!(trim_bytes(b"hi"), b"hi");
+	}
+
+	#[test]
+	fn trim_bytes_04() {
+		assert_eq!(trim_bytes(b"\x0bGotta catch\tthem all\x0c"), b"Gotta catch\tthem all");
+	}
+
+	#[test]
+	fn split_bytes_00() {
+		let mut split: ByteSplit<'_> = split_bytes(b"", b',');
+
+		assert_eq!(split.next(), Some(&b""[..]));
+		assert_eq!(split.next(), None);
+		assert_eq!(split.next(), None);
+	}
+
+	#[test]
+	fn split_bytes_01() {
+		let mut split: ByteSplit<'_> = split_bytes(b"abc", b',');
+
+		assert_eq!(split.next(), Some(&b"abc"[..]));
+		assert_eq!(split.next(), None);
+	}
+
+	#[test]
+	fn split_bytes_02() {
+		let mut split: ByteSplit<'_> = split_bytes(b"a,b,,c", b',');
+
+		assert_eq!(split.next(), Some(&b"a"[..]));
+		assert_eq!(split.next(), Some(&b"b"[..]));
+		assert_eq!(split.next(), Some(&b""[..]));
+		assert_eq!(split.next(), Some(&b"c"[..]));
+		assert_eq!(split.next(), None);
+	}
+
+	#[test]
+	fn split_bytes_03() {
+		let mut split: ByteSplit<'_> = split_bytes(b",a,", b',');
+
+		assert_eq!(split.next(), Some(&b""[..]));
+		assert_eq!(split.next(), Some(&b"a"[..]));
+		assert_eq!(split.next(), Some(&b""[..]));
+		assert_eq!(split.next(), None);
+	}
+
+	#[test]
+	fn split_bytes_04() {
+		const S: &str = "Gotta catch them all";
+
+		assert!(split_bytes(S.as_bytes(), b' ').eq(S.split(' ').map(str::as_bytes)));
+	}
+
+	#[test]
+	fn is_anagram_00() {
+		assert_eq!(is_anagram(b"", b""), true);
+	}
+
+	#[test]
+	fn is_anagram_01() {
+		assert_eq!(is_anagram(b"listen", b"silent"), true);
+	}
+
+	#[test]
+	fn is_anagram_02() {
+		assert_eq!(is_anagram(b"listen", b"silents"), false);
+	}
+
+	#[test]
+	fn is_anagram_03() {
+		assert_eq!(is_anagram(b"aab", b"abb"), false);
+	}
+
+	#[test]
+	fn is_anagram_04() {
+		assert_eq!(is_anagram(b"Listen", b"silent"), false);
+	}
+
+	#[test]
+	fn is_anagram_05() {
+		assert_eq!(is_anagram(b"\x00\xff\x80\xff", b"\xff\x80\xff\x00"), true);
+	}
+
+	#[test]
+	fn common_prefix_len_00() {
+		assert_eq!(common_prefix_len(b"", b""), 0);
+	}
+
+	#[test]
+	fn common_prefix_len_01() {
+		assert_eq!(common_prefix_len(b"abcx", b"abcy"), 3);
+	}
+
+	#[test]
+	fn common_prefix_len_02() {
+		assert_eq!(common_prefix_len(b"abc", b"xyz"), 0);
+	}
+
+	#[test]
+	fn common_prefix_len_03() {
+		assert_eq!(common_prefix_len(b"abc", b"abcdef"), 3);
+	}
+
+	#[test]
+	fn common_prefix_len_04() {
+		assert_eq!(common_prefix_len(b"abcdef", b"abc"), 3);
+	}
+
+	#[test]
+	fn common_prefix_len_05() {
+		assert_eq!(common_prefix_len(b"Hello World!", b"Hello World!"), 12);
+	}
+
+	#[test]
+	fn common_prefix_len_06() {
+		assert_eq!(common_prefix_len(b"", b"abc"), 0);
+	}
+
+	#[test]
+	fn find_char_00() {
+		assert_eq!(find_char("", 'a'), None);
+	}
+
+	#[test]
+	fn find_char_01() {
+		assert_eq!(find_char("Hello World!", 'o'), Some(4));
+	}
+
+	#[test]
+	fn find_char_02() {
+		assert_eq!(find_char("Hello World!", 'z'), None);
+	}
+
+	#[test]
+	fn find_char_03() {
+		assert_eq!(find_char("déjà vu", 'v'), Some(7));
+		assert_eq!(find_char("déjà vu", 'à'), Some(4));
+	}
+
+	#[test]
+	fn find_char_04() {
+		assert_eq!(find_char("naïve café", 'é'), Some(10));
+		assert_eq!(find_char("naïve café", 'è'), None);
+	}
+
+	#[test]
+	fn find_char_05() {
+		assert_eq!(find_char("🦀 crab 🦀", '🦀'), Some(0));
+	}
+
+	#[test]
+	fn rfind_char_00() {
+		assert_eq!(rfind_char("", 'a'), None);
+	}
+
+	#[test]
+	fn rfind_char_01() {
+		assert_eq!(rfind_char("Hello World!", 'o'), Some(7));
+	}
+
+	#[test]
+	fn rfind_char_02() {
+		assert_eq!(rfind_char("Hello World!", 'z'), None);
+	}
+
+	#[test]
+	fn rfind_char_03() {
+		assert_eq!(rfind_char("déjà vu", 'u'), Some(8));
+		assert_eq!(rfind_char("déjà vu", 'é'), Some(1));
+	}
+
+	#[test]
+	fn rfind_char_04() {
+		assert_eq!(rfind_char("🦀 crab 🦀", '🦀'), Some(10));
+	}
+
+	#[test]
+	fn find_sub_00() {
+		assert_eq!(find_sub("", ""), Some(0));
+		assert_eq!(find_sub("déjà vu", ""), Some(0));
+	}
+
+	#[test]
+	fn find_sub_01() {
+		assert_eq!(find_sub("", "a"), None);
+	}
+
+	#[test]
+	fn find_sub_02() {
+		assert_eq!(find_sub("Hello World!", "World"), Some(6));
+	}
+
+	#[test]
+	fn find_sub_03() {
+		assert_eq!(find_sub("déjà vu", "vu"), Some(7));
+		assert_eq!(find_sub("déjà vu", "jà"), Some(3));
+	}
+
+	#[test]
+	fn find_sub_04() {
+		assert_eq!(find_sub("naïve café", "café"), Some(7));
+		assert_eq!(find_sub("naïve café", "cafe"), None);
+	}
+
+	#[test]
+	fn find_sub_05() {
+		assert_eq!(find_sub("🦀 crab 🦀", "🦀"), Some(0));
+		assert_eq!(find_sub("🦀 crab 🦀", "b 🦀"), Some(8));
+	}
 }
@@ -1,3 +1,5 @@
+use std::ops::Add;
+
 /// Adds two numbers together.
 ///
 /// ### Parameters
@@ -13,8 +15,32 @@
 ///
 /// assert_eq!(add(&2, 3), 5);
 /// ```
+#[inline(always)]
 pub fn add(a: &i32, b: i32) -> i32 {
-	return a + b;
+	return add_generic(a, b);
+}
+
+/// Adds two values of any addable type together.
+///
+/// ### Type parameters
+/// * `T` - The type of the values to add.
+///
+/// ### Parameters
+/// * `a` - The first value.
+/// * `b` - The second value.
+///
+/// ### Returns
+/// The sum of `a` and `b`.
+///
+/// ### Example
+/// ```
+/// use ex00::add_generic;
+///
+/// assert_eq!(add_generic(&2u8, 3u8), 5u8);
+/// assert_eq!(add_generic(&0.5f64, 0.25f64), 0.75f64);
+/// ```
+pub fn add_generic<T: Add<Output = T> + Copy>(a: &T, b: T) -> T {
+	return *a + b;
 }
 
 /// Adds two numbers together, and store the result in the first given argument.
@@ -36,10 +62,62 @@ pub fn add_assign(a: &mut i32, b: i32) {
 	*a += b;
 }
 
+/// Adds two numbers together, checking for overflow.
+///
+/// ### Parameters
+/// * `a` - The first number.
+/// * `b` - The second number.
+///
+/// ### Returns
+/// * `Some(i32)` - The sum of `a` and `b`.
+/// * `None` - The sum of `a` and `b` overflows.
+///
+/// ### Example
+/// ```
+/// use ex00::checked_add;
+///
+/// assert_eq!(checked_add(&2, 3), Some(5));
+/// assert_eq!(checked_add(&i32::MAX, 1), None);
+/// ```
+pub fn checked_add(a: &i32, b: i32) -> Option<i32> {
+	return a.checked_add(b);
+}
+
+/// Adds two numbers together, saturating at the numeric bounds instead of overflowing.
+///
+/// ### Parameters
+/// * `a` - The first number.
+/// * `b` - The second number.
+///
+/// ### Returns
+/// The sum of `a` and `b`, clamped to the range of `i32`.
+///
+/// ### Example
+/// ```
+/// use ex00::saturating_add;
+///
+/// assert_eq!(saturating_add(&2, 3), 5);
+/// assert_eq!(saturating_add(&i32::MAX, 1), i32::MAX);
+/// ```
+pub fn saturating_add(a: &i32, b: i32) -> i32 {
+	return a.saturating_add(b);
+}
+
 #[cfg(test)]
 mod test {
 	use super::*;
 
+	#[derive(Clone, Copy, Debug, PartialEq)]
+	struct Meters(u32);
+
+	impl Add for Meters {
+		type Output = Meters;
+
+		fn add(self: Self, rhs: Self) -> Self::Output {
+			return Meters(self.0 + rhs.0);
+		}
+	}
+
 	#[test]
 	fn add_00() {
 		assert_eq!(add(&0, 0), 0);
@@ -182,4 +260,89 @@ mod test {
 		add_assign(&mut a, i32::MAX);
 		assert_eq!(a, -1);
 	}
+
+	#[test]
+	fn checked_add_00() {
+		assert_eq!(checked_add(&0, 0), Some(0));
+	}
+
+	#[test]
+	fn checked_add_01() {
+		assert_eq!(checked_add(&-1, -1), Some(-2));
+	}
+
+	#[test]
+	fn checked_add_02() {
+		assert_eq!(checked_add(&i32::MAX, i32::MIN), Some(-1));
+	}
+
+	#[test]
+	fn checked_add_03() {
+		assert_eq!(checked_add(&i32::MAX, 1), None);
+	}
+
+	#[test]
+	fn checked_add_04() {
+		assert_eq!(checked_add(&i32::MIN, -1), None);
+	}
+
+	#[test]
+	fn saturating_add_00() {
+		assert_eq!(saturating_add(&0, 0), 0);
+	}
+
+	#[test]
+	fn saturating_add_01() {
+		assert_eq!(saturating_add(&-1, -1), -2);
+	}
+
+	#[test]
+	fn saturating_add_02() {
+		assert_eq!(saturating_add(&i32::MAX, i32::MIN), -1);
+	}
+
+	#[test]
+	fn saturating_add_03() {
+		assert_eq!(saturating_add(&i32::MAX, 1), i32::MAX);
+	}
+
+	#[test]
+	fn saturating_add_04() {
+		assert_eq!(saturating_add(&i32::MIN, -1), i32::MIN);
+	}
+
+	#[test]
+	fn add_generic_00() {
+		assert_eq!(add_generic(&0u8, 0u8), 0u8);
+	}
+
+	#[test]
+	fn add_generic_01() {
+		assert_eq!(add_generic(&u8::MAX, 0u8), u8::MAX);
+	}
+
+	#[test]
+	fn add_generic_02() {
+		assert_eq!(add_generic(&1.5f64, -0.25f64), 1.25f64);
+	}
+
+	#[test]
+	fn add_generic_03() {
+		assert_eq!(add_generic(&f64::MAX, f64::MAX), f64::INFINITY);
+	}
+
+	#[test]
+	fn add_generic_04() {
+		assert_eq!(add_generic(&f64::NEG_INFINITY, 42.0f64), f64::NEG_INFINITY);
+	}
+
+	#[test]
+	fn add_generic_05() {
+		assert_eq!(add_generic(&Meters(21), Meters(21)), Meters(42));
+	}
+
+	#[test]
+	fn add_generic_06() {
+		assert_eq!(add_generic(&Meters(0), Meters(u32::MAX)), Meters(u32::MAX));
+	}
 }
@@ -25,6 +25,54 @@ pub fn color_name(color: &[u8; 3]) -> &'static str {
 	}
 }
 
+/// Gets the name of every color of a buffer from its RGB value.
+///
+/// ### Parameters
+/// * `pixels` - A slice of RGB values.
+///
+/// ### Return
+/// An iterator that lazily yields the name of each color of `pixels`, in order.
+///
+/// ### Example
+/// ```
+/// use ex02::color_names;
+///
+/// let names: Vec<&str> = color_names(&[[255, 0, 0], [0, 0, 0]]).collect();
+///
+/// assert_eq!(names, ["pure red", "pure black"]);
+/// ```
+pub fn color_names<'a>(pixels: &'a [[u8; 3]]) -> impl Iterator<Item = &'static str> + 'a {
+	pixels.iter().map(color_name)
+}
+
+/// Gets a representative RGB value of a color from its name.
+///
+/// ### Parameters
+/// * `name` - The name of the color, as returned by `color_name`.
+///
+/// ### Return
+/// * `Some([u8; 3])` - The RGB value of the color.
+/// * `None` - The name is unknown, or it does not match a single RGB value.
+///
+/// ### Example
+/// ```
+/// use ex02::name_to_color;
+///
+/// assert_eq!(name_to_color("pure red"), Some([255, 0, 0]));
+/// assert_eq!(name_to_color("redish"), None);
+/// ```
+pub fn name_to_color(name: &str) -> Option<[u8; 3]> {
+	match name {
+		"pure black" => Some([0, 0, 0]),
+		"pure white" => Some([255, 255, 255]),
+		"pure red" => Some([255, 0, 0]),
+		"pure green" => Some([0, 255, 0]),
+		"pure blue" => Some([0, 0, 255]),
+		"perfect grey" => Some([128, 128, 128]),
+		_ => None,
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -344,4 +392,82 @@ mod tests {
 			}
 		}
 	}
+
+	#[test]
+	fn color_names_00() {
+		let pixels: [[u8; 3]; 0] = [];
+
+		assert_eq!(color_names(&pixels).next(), None);
+	}
+
+	#[test]
+	fn color_names_01() {
+		let names: Vec<&str> = {
+			let pixels: [[u8; 3]; 11] = [
+				[0, 0, 0],
+				[255, 255, 255],
+				[255, 0, 0],
+				[0, 255, 0],
+				[0, 0, 255],
+				[128, 128, 128],
+				[12, 30, 0],
+				[200, 100, 50],
+				[42, 142, 21],
+				[0, 127, 129],
+				[128, 128, 129],
+			];
+
+			color_names(&pixels).collect()
+		};
+
+		assert_eq!(
+			names,
+			[
+				"pure black",
+				"pure white",
+				"pure red",
+				"pure green",
+				"pure blue",
+				"perfect grey",
+				"almost black",
+				"redish",
+				"greenish",
+				"blueish",
+				"unknown",
+			]
+		);
+	}
+
+	#[test]
+	fn name_to_color_00() {
+		for name in
+			["pure black", "pure white", "pure red", "pure green", "pure blue", "perfect grey"]
+		{
+			let color: [u8; 3] = match name_to_color(name) {
+				Some(color) => color,
+				None => panic!("no color for {}", name),
+			};
+
+			assert_eq!(color_name(&color), name);
+		}
+	}
+
+	#[test]
+	fn name_to_color_01() {
+		assert_eq!(name_to_color("perfect grey"), Some([128, 128, 128]));
+	}
+
+	#[test]
+	fn name_to_color_02() {
+		for name in ["almost black", "redish", "greenish", "blueish", "unknown"] {
+			assert_eq!(name_to_color(name), None);
+		}
+	}
+
+	#[test]
+	fn name_to_color_03() {
+		assert_eq!(name_to_color(""), None);
+		assert_eq!(name_to_color("Pure Red"), None);
+		assert_eq!(name_to_color("pure red "), None);
+	}
 }
@@ -1,5 +1,8 @@
 /// Searches for the largest subslice of a slice that contains all and only specified values.
 ///
+/// ### Type parameters
+/// * `T` - The type of the values to compare.
+///
 /// ### Parameters
 /// * `haystack` - The slice to search in.
 /// * `needle` - The slice of values to search for.
@@ -13,9 +16,39 @@
 ///
 /// assert_eq!(largest_group(&[1, 3, 4, 3, 5, 5, 4], &[5, 3]), &[3, 5, 5]);
 /// ```
-pub fn largest_group<'a>(haystack: &'a [u32], needle: &[u32]) -> &'a [u32] {
-	let mut best_so_far: &[u32] = &[];
-	let mut candidate: &[u32];
+pub fn largest_group<'a, T: PartialEq>(haystack: &'a [T], needle: &[T]) -> &'a [T] {
+	let mut best_so_far: &[T] = &[];
+
+	for candidate in all_groups(haystack, needle) {
+		if candidate.len() > best_so_far.len() {
+			best_so_far = candidate;
+		}
+	}
+	best_so_far
+}
+
+/// Searches for every maximal subslice of a slice that contains all and only specified values.
+///
+/// ### Type parameters
+/// * `T` - The type of the values to compare.
+///
+/// ### Parameters
+/// * `haystack` - The slice to search in.
+/// * `needle` - The slice of values to search for.
+///
+/// ### Return
+/// The maximal subslices of `haystack` that contain all and only values in `needles`,
+/// in the order they appear in `haystack`.
+///
+/// ### Example
+/// ```
+/// use ex03::all_groups;
+///
+/// assert_eq!(all_groups(&[1, 3, 4, 3, 5, 5, 4, 5, 3], &[5, 3]), [&[3, 5, 5][..], &[5, 3][..]]);
+/// ```
+pub fn all_groups<'a, T: PartialEq>(haystack: &'a [T], needle: &[T]) -> Vec<&'a [T]> {
+	let mut groups: Vec<&[T]> = Vec::new();
+	let mut candidate: &[T];
 	let mut i0: usize = 0;
 	let mut i1: usize;
 
@@ -28,21 +61,12 @@ pub fn largest_group<'a>(haystack: &'a [u32], needle: &[u32]) -> &'a [u32] {
 			i1 += 1;
 		}
 		candidate = &haystack[i0..i1];
-		if candidate.len() > best_so_far.len() {
-			i0 = 0;
-			while i0 < needle.len() {
-				if !candidate.contains(&needle[i0]) {
-					break;
-				}
-				i0 += 1;
-			}
-			if i0 == needle.len() {
-				best_so_far = candidate;
-			}
+		if !candidate.is_empty() && needle.iter().all(|value: &T| candidate.contains(value)) {
+			groups.push(candidate);
 		}
 		i0 = i1;
 	}
-	return best_so_far;
+	groups
 }
 
 #[cfg(test)]
@@ -156,4 +180,136 @@ mod test {
 
 		assert_eq!(result, &[2, 2, 1, 2]);
 	}
+
+	#[test]
+	fn largest_group_09() {
+		let haystack: [char; 9] = ['a', 'b', 'c', 'b', 'a', 'a', 'c', 'a', 'b'];
+		let result: &[char] = {
+			let needle: [char; 2] = ['a', 'b'];
+
+			largest_group(&haystack, &needle)
+		};
+
+		assert_eq!(result, &['b', 'a', 'a']);
+	}
+
+	#[test]
+	fn largest_group_10() {
+		let haystack: [char; 4] = ['a', 'a', 'c', 'b'];
+		let result: &[char] = {
+			let needle: [char; 2] = ['a', 'b'];
+
+			largest_group(&haystack, &needle)
+		};
+
+		assert_eq!(result, &[]);
+	}
+
+	#[test]
+	fn largest_group_11() {
+		let haystack: [String; 6] = ["to", "be", "or", "not", "to", "be"].map(String::from);
+		let result: &[String] = {
+			let needle: [String; 2] = ["be".to_string(), "to".to_string()];
+
+			largest_group(&haystack, &needle)
+		};
+
+		assert_eq!(result, ["to".to_string(), "be".to_string()]);
+	}
+
+	#[test]
+	fn all_groups_00() {
+		let haystack: [u32; 0] = [];
+		let result: Vec<&[u32]> = {
+			let needle: [u32; 0] = [];
+
+			all_groups(&haystack, &needle)
+		};
+
+		assert_eq!(result, [] as [&[u32]; 0]);
+	}
+
+	#[test]
+	fn all_groups_01() {
+		let haystack: [u32; 3] = [1, 2, 3];
+		let result: Vec<&[u32]> = {
+			let needle: [u32; 0] = [];
+
+			all_groups(&haystack, &needle)
+		};
+
+		assert_eq!(result, [] as [&[u32]; 0]);
+	}
+
+	#[test]
+	fn all_groups_02() {
+		let haystack: [u32; 3] = [1, 2, 3];
+		let result: Vec<&[u32]> = {
+			let needle: [u32; 3] = [4, 5, 6];
+
+			all_groups(&haystack, &needle)
+		};
+
+		assert_eq!(result, [] as [&[u32]; 0]);
+	}
+
+	#[test]
+	fn all_groups_03() {
+		let haystack: [u32; 3] = [1, 2, 3];
+		let result: Vec<&[u32]> = {
+			let needle: [u32; 3] = [1, 2, 3];
+
+			all_groups(&haystack, &needle)
+		};
+
+		assert_eq!(result, [&[1, 2, 3][..]]);
+	}
+
+	#[test]
+	fn all_groups_04() {
+		let haystack: [u32; 9] = [1, 2, 3, 4, 3, 2, 1, 2, 3];
+		let result: Vec<&[u32]> = {
+			let needle: [u32; 3] = [1, 2, 3];
+
+			all_groups(&haystack, &needle)
+		};
+
+		assert_eq!(result, [&[1, 2, 3][..], &[3, 2, 1, 2, 3][..]]);
+	}
+
+	#[test]
+	fn all_groups_05() {
+		let haystack: [u32; 9] = [1, 2, 3, 4, 3, 2, 4, 2, 3];
+		let result: Vec<&[u32]> = {
+			let needle: [u32; 3] = [1, 2, 3];
+
+			all_groups(&haystack, &needle)
+		};
+
+		assert_eq!(result, [&[1, 2, 3][..]]);
+	}
+
+	#[test]
+	fn all_groups_06() {
+		let haystack: [u32; 10] = [1, 2, 5, 2, 1, 5, 1, 1, 5, 2];
+		let result: Vec<&[u32]> = {
+			let needle: [u32; 2] = [1, 2];
+
+			all_groups(&haystack, &needle)
+		};
+
+		assert_eq!(result, [&[1, 2][..], &[2, 1][..]]);
+	}
+
+	#[test]
+	fn all_groups_07() {
+		let haystack: [char; 9] = ['a', 'b', 'c', 'b', 'a', 'a', 'c', 'a', 'b'];
+		let result: Vec<&[char]> = {
+			let needle: [char; 2] = ['a', 'b'];
+
+			all_groups(&haystack, &needle)
+		};
+
+		assert_eq!(result, [&['a', 'b'][..], &['b', 'a', 'a'][..], &['a', 'b'][..]]);
+	}
 }
@@ -1,11 +1,11 @@
 fn __partition(boxes: &mut [[u32; 2]], mut low: usize, mut high: usize) -> usize {
-	let pivot: u32 = boxes[(high - low) / 2 + low][0];
+	let pivot: [u32; 2] = boxes[(high - low) / 2 + low];
 
 	loop {
-		while boxes[low][0] > pivot {
+		while boxes[low] > pivot {
 			low = low + 1;
 		}
-		while boxes[high][0] < pivot {
+		while boxes[high] < pivot {
 			high = high - 1;
 		}
 		if low >= high {
@@ -27,6 +27,15 @@ fn __quicksort(boxes: &mut [[u32; 2]], low: usize, high: usize) {
 	}
 }
 
+/// The direction in which boxes are sorted.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Order {
+	/// Every box contains the previous one.
+	Ascending,
+	/// Every box is contained in the previous one.
+	Descending,
+}
+
 /// Sorts boxes in a way for every box to be contained in the previous one.
 ///
 /// ### Parameters
@@ -46,17 +55,74 @@ fn __quicksort(boxes: &mut [[u32; 2]], low: usize, high: usize) {
 /// assert_eq!(boxes, [[4, 5], [3, 4], [2, 3], [1, 2]]);
 /// ```
 pub fn sort_boxes(boxes: &mut [[u32; 2]]) {
+	if try_sort_boxes(boxes).is_err() {
+		panic!("Sorting is not possible");
+	}
+}
+
+/// Tries to sort boxes in a way for every box to be contained in the previous one.
+/// Whether such a sorting is possible or not, the boxes end up sorted in descending order
+/// by their first dimension, then by their second dimension.
+///
+/// ### Parameters
+/// * `boxes` - A slice containing the boxes to sort.
+///
+/// ### Return
+/// * `Ok(())` - Every box is contained in the previous one.
+/// * `Err(())` - No such sorting is possible.
+///
+/// ### Example
+/// ```
+/// use ex04::try_sort_boxes;
+///
+/// let mut boxes: [[u32; 2]; 3] = [[1, 2], [3, 1], [2, 3]];
+///
+/// assert_eq!(try_sort_boxes(&mut boxes), Err(()));
+/// assert_eq!(boxes, [[3, 1], [2, 3], [1, 2]]);
+/// ```
+#[allow(clippy::result_unit_err)]
+pub fn try_sort_boxes(boxes: &mut [[u32; 2]]) -> Result<(), ()> {
 	if boxes.len() < 2 {
-		return;
+		return Ok(());
 	}
 
 	__quicksort(boxes, 0, boxes.len() - 1);
 
 	for i in 0..boxes.len() - 1 {
 		if boxes[i][1] < boxes[i + 1][1] {
-			panic!("Sorting is not possible");
+			return Err(());
 		}
 	}
+
+	Ok(())
+}
+
+/// Sorts boxes in a way for every box to either contain the previous one,
+/// or be contained in the previous one, depending on the given order.
+///
+/// ### Parameters
+/// * `boxes` - A slice containing the boxes to sort.
+/// * `order` - The direction in which the boxes are sorted.
+///
+/// ### Panic
+/// No such sorting is possible.
+///
+/// ### Example
+/// ```
+/// use ex04::{sort_boxes_ordered, Order};
+///
+/// let mut boxes: [[u32; 2]; 4] = [[3, 4], [1, 2], [4, 5], [2, 3]];
+///
+/// sort_boxes_ordered(&mut boxes, Order::Ascending);
+///
+/// assert_eq!(boxes, [[1, 2], [2, 3], [3, 4], [4, 5]]);
+/// ```
+pub fn sort_boxes_ordered(boxes: &mut [[u32; 2]], order: Order) {
+	sort_boxes(boxes);
+
+	if order == Order::Ascending {
+		boxes.reverse();
+	}
 }
 
 #[cfg(test)]
@@ -163,4 +229,100 @@ mod test {
 
 		sort_boxes(&mut boxes);
 	}
+
+	#[test]
+	fn sort_boxes_ordered_00() {
+		let mut boxes: [[u32; 2]; 0] = [];
+
+		sort_boxes_ordered(&mut boxes, Order::Ascending);
+		assert_eq!(boxes, [] as [[u32; 2]; 0]);
+	}
+
+	#[test]
+	fn sort_boxes_ordered_01() {
+		let mut boxes: [[u32; 2]; 5] = [[3, 4], [1, 2], [5, 6], [4, 5], [2, 3]];
+
+		sort_boxes_ordered(&mut boxes, Order::Ascending);
+		assert_eq!(boxes, [[1, 2], [2, 3], [3, 4], [4, 5], [5, 6]]);
+	}
+
+	#[test]
+	fn sort_boxes_ordered_02() {
+		let mut boxes: [[u32; 2]; 5] = [[3, 4], [1, 2], [5, 6], [4, 5], [2, 3]];
+
+		sort_boxes_ordered(&mut boxes, Order::Descending);
+		assert_eq!(boxes, [[5, 6], [4, 5], [3, 4], [2, 3], [1, 2]]);
+	}
+
+	#[test]
+	fn sort_boxes_ordered_03() {
+		let mut boxes: [[u32; 2]; 3] = [[2, 2], [1, 1], [2, 2]];
+
+		sort_boxes_ordered(&mut boxes, Order::Ascending);
+		assert_eq!(boxes, [[1, 1], [2, 2], [2, 2]]);
+	}
+
+	#[test]
+	#[should_panic(expected = "Sorting is not possible")]
+	fn sort_boxes_ordered_04() {
+		let mut boxes: [[u32; 2]; 2] = [[1, 3], [2, 2]];
+
+		sort_boxes_ordered(&mut boxes, Order::Ascending);
+	}
+
+	#[test]
+	#[should_panic(expected = "Sorting is not possible")]
+	fn sort_boxes_ordered_05() {
+		let mut boxes: [[u32; 2]; 2] = [[3, 1], [2, 2]];
+
+		sort_boxes_ordered(&mut boxes, Order::Descending);
+	}
+
+	#[test]
+	fn try_sort_boxes_00() {
+		let mut boxes: [[u32; 2]; 0] = [];
+
+		assert_eq!(try_sort_boxes(&mut boxes), Ok(()));
+		assert_eq!(boxes, [] as [[u32; 2]; 0]);
+	}
+
+	#[test]
+	fn try_sort_boxes_01() {
+		let mut boxes: [[u32; 2]; 3] = [[1, 2], [3, 4], [2, 3]];
+
+		assert_eq!(try_sort_boxes(&mut boxes), Ok(()));
+		assert_eq!(boxes, [[3, 4], [2, 3], [1, 2]]);
+	}
+
+	#[test]
+	fn try_sort_boxes_02() {
+		let mut boxes: [[u32; 2]; 2] = [[1, 3], [2, 2]];
+
+		assert_eq!(try_sort_boxes(&mut boxes), Err(()));
+		assert_eq!(boxes, [[2, 2], [1, 3]]);
+	}
+
+	#[test]
+	fn try_sort_boxes_03() {
+		let mut boxes: [[u32; 2]; 4] = [[1, 1], [4, 1], [2, 5], [3, 3]];
+
+		assert_eq!(try_sort_boxes(&mut boxes), Err(()));
+		assert_eq!(boxes, [[4, 1], [3, 3], [2, 5], [1, 1]]);
+	}
+
+	#[test]
+	fn try_sort_boxes_04() {
+		let mut boxes: [[u32; 2]; 2] = [[1, 2], [1, 3]];
+
+		assert_eq!(try_sort_boxes(&mut boxes), Ok(()));
+		assert_eq!(boxes, [[1, 3], [1, 2]]);
+	}
+
+	#[test]
+	fn try_sort_boxes_05() {
+		let mut boxes: [[u32; 2]; 6] = [[2, 2], [1, 3], [2, 1], [1, 3], [2, 3], [1, 1]];
+
+		assert_eq!(try_sort_boxes(&mut boxes), Err(()));
+		assert_eq!(boxes, [[2, 3], [2, 2], [2, 1], [1, 3], [1, 3], [1, 1]]);
+	}
 }
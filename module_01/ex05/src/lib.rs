@@ -1,7 +1,11 @@
 /// Removes every duplicate element from a vector.
+/// The first occurrence of each element is kept, in its original order.
+///
+/// ### Type parameters
+/// * `T` - The type of the elements of the vector.
 ///
 /// ### Parameters
-/// * `v` - The vector of integers to remove the duplicate elements from.
+/// * `v` - The vector to remove the duplicate elements from.
 ///
 /// ### Example
 /// ```
@@ -11,22 +15,232 @@
 /// deduplicate(&mut v);
 /// assert_eq!(v, [1, 2, 3, 4]);
 /// ```
-pub fn deduplicate(v: &mut Vec<i32>) {
-	let mut i: usize;
-	let mut j: usize;
+pub fn deduplicate<T: PartialEq>(v: &mut Vec<T>) {
+	let mut kept: usize = 0;
+
+	for i in 0..v.len() {
+		if !v[..kept].contains(&v[i]) {
+			v.swap(kept, i);
+			kept += 1;
+		}
+	}
+	v.truncate(kept);
+}
+
+/// Removes every duplicate element from a vector, using a hash set to track the seen elements.
+/// The first occurrence of each element is kept, in its original order.
+///
+/// ### Type parameters
+/// * `T` - The type of the elements of the vector.
+///
+/// ### Parameters
+/// * `v` - The vector to remove the duplicate elements from.
+///
+/// ### Example
+/// ```
+/// use ex05::deduplicate_hashed;
+///
+/// let mut v = vec!['a', 'b', 'b', 'c', 'b', 'd', 'c'];
+/// deduplicate_hashed(&mut v);
+/// assert_eq!(v, ['a', 'b', 'c', 'd']);
+/// ```
+pub fn deduplicate_hashed<T: Eq + std::hash::Hash>(v: &mut Vec<T>) {
+	use std::collections::HashSet;
+
+	let mut seen: HashSet<&T> = HashSet::with_capacity(v.len());
+	let is_first_occurrence: Vec<bool> = v.iter().map(|x: &T| seen.insert(x)).collect();
+	let mut is_first_occurrence: std::vec::IntoIter<bool> = is_first_occurrence.into_iter();
+
+	v.retain(|_| is_first_occurrence.next() == Some(true));
+}
+
+/// Removes every duplicate element from a sorted vector, in a single pass.
+///
+/// The vector is assumed to be sorted in ascending order, so that duplicate elements
+/// are next to each other. If it is not, only consecutive duplicates are removed:
+/// the result is unspecified, but the function does not panic.
+///
+/// ### Parameters
+/// * `v` - The sorted vector to remove the duplicate elements from.
+///
+/// ### Example
+/// ```
+/// use ex05::deduplicate_sorted;
+///
+/// let mut v = vec![1, 1, 2, 3, 3, 3, 4];
+/// deduplicate_sorted(&mut v);
+/// assert_eq!(v, [1, 2, 3, 4]);
+/// ```
+pub fn deduplicate_sorted(v: &mut Vec<i32>) {
+	let mut kept: usize = 0;
+
+	for i in 0..v.len() {
+		if kept == 0 || v[kept - 1] != v[i] {
+			v[kept] = v[i];
+			kept += 1;
+		}
+	}
+	v.truncate(kept);
+}
+
+/// Computes the running product of a slice of integers.
+///
+/// ### Parameters
+/// * `v` - The slice of integers to compute the running product of.
+///
+/// ### Return
+/// A vector containing, for each element of `v`, the product of every element up to it.
+/// The products are stored as `i128` to reduce the risk of overflow.
+///
+/// ### Panic
+/// A product overflows `i128`.
+///
+/// ### Example
+/// ```
+/// use ex05::running_product;
+///
+/// assert_eq!(running_product(&[1, 2, 3, 4]), [1, 2, 6, 24]);
+/// ```
+pub fn running_product(v: &[i64]) -> Vec<i128> {
+	let mut result: Vec<i128> = Vec::with_capacity(v.len());
+	let mut product: i128 = 1;
+
+	for &n in v {
+		product = match product.checked_mul(n as i128) {
+			Some(product) => product,
+			None => panic!("Product overflow"),
+		};
+		result.push(product);
+	}
+
+	result
+}
+
+/// Splits a slice of integers into consecutive chunks of a given size.
+/// The last chunk may be shorter if the length of the slice is not a multiple of `size`.
+///
+/// ### Parameters
+/// * `v` - The slice of integers to split.
+/// * `size` - The size of each chunk.
+///
+/// ### Return
+/// A vector containing the chunks, in order.
+///
+/// ### Panic
+/// `size` is zero.
+///
+/// ### Example
+/// ```
+/// use ex05::chunk;
+///
+/// assert_eq!(chunk(&[1, 2, 3, 4, 5], 2), [vec![1, 2], vec![3, 4], vec![5]]);
+/// ```
+pub fn chunk(v: &[i32], size: usize) -> Vec<Vec<i32>> {
+	assert!(size != 0, "Invalid chunk size");
+
+	let mut result: Vec<Vec<i32>> = Vec::with_capacity(v.len().div_ceil(size));
+	let mut i: usize = 0;
 
-	i = 0;
 	while i < v.len() {
-		j = i + 1;
-		while j < v.len() {
-			if v[i] == v[j] {
-				v.remove(j);
-			} else {
-				j += 1;
-			}
+		result.push(v[i..v.len().min(i + size)].to_vec());
+		i += size;
+	}
+
+	result
+}
+
+/// Concatenates a slice of vectors of integers into a single vector.
+///
+/// ### Parameters
+/// * `vv` - The slice of vectors to concatenate.
+///
+/// ### Return
+/// A vector containing the elements of every inner vector, in order.
+///
+/// ### Example
+/// ```
+/// use ex05::flatten;
+///
+/// assert_eq!(flatten(&[vec![1, 2], vec![3]]), [1, 2, 3]);
+/// ```
+pub fn flatten(vv: &[Vec<i32>]) -> Vec<i32> {
+	let mut result: Vec<i32> = Vec::with_capacity(vv.iter().map(Vec::len).sum());
+
+	for v in vv {
+		result.extend_from_slice(v);
+	}
+
+	result
+}
+
+/// Computes the intersection of two slices of integers.
+///
+/// ### Parameters
+/// * `a` - The first slice of integers.
+/// * `b` - The second slice of integers.
+///
+/// ### Return
+/// A vector containing every distinct value present in both `a` and `b`,
+/// in the order of their first occurrence in `a`.
+///
+/// ### Example
+/// ```
+/// use ex05::intersection;
+///
+/// assert_eq!(intersection(&[1, 2, 3, 2], &[2, 3, 4]), [2, 3]);
+/// ```
+pub fn intersection(a: &[i32], b: &[i32]) -> Vec<i32> {
+	use std::collections::HashSet;
+
+	let mut in_b: HashSet<i32> = b.iter().copied().collect();
+	let mut result: Vec<i32> = Vec::new();
+
+	for &n in a {
+		if in_b.remove(&n) {
+			result.push(n);
+		}
+	}
+
+	result
+}
+
+/// Computes the symmetric difference of two slices of integers.
+///
+/// ### Parameters
+/// * `a` - The first slice of integers.
+/// * `b` - The second slice of integers.
+///
+/// ### Return
+/// A vector containing every distinct value present in exactly one of `a` and `b`.
+/// The values only present in `a` come first, in the order of their first occurrence in `a`,
+/// followed by the values only present in `b`, in the order of their first occurrence in `b`.
+///
+/// ### Example
+/// ```
+/// use ex05::symmetric_difference;
+///
+/// assert_eq!(symmetric_difference(&[1, 2, 3], &[2, 3, 4]), [1, 4]);
+/// ```
+pub fn symmetric_difference(a: &[i32], b: &[i32]) -> Vec<i32> {
+	use std::collections::HashSet;
+
+	let in_a: HashSet<i32> = a.iter().copied().collect();
+	let in_b: HashSet<i32> = b.iter().copied().collect();
+	let mut seen: HashSet<i32> = HashSet::new();
+	let mut result: Vec<i32> = Vec::new();
+
+	for &n in a {
+		if !in_b.contains(&n) && seen.insert(n) {
+			result.push(n);
 		}
-		i += 1;
 	}
+	for &n in b {
+		if !in_a.contains(&n) && seen.insert(n) {
+			result.push(n);
+		}
+	}
+
+	result
 }
 
 #[cfg(test)]
@@ -83,4 +297,258 @@ mod tests {
 		deduplicate(&mut v);
 		assert_eq!(v, [i32::MIN, i32::MAX, 0, 7]);
 	}
+
+	#[test]
+	fn deduplicate_06() {
+		let mut v: Vec<String> = ["foo", "bar", "foo", "baz", "bar"].map(String::from).to_vec();
+
+		deduplicate(&mut v);
+		assert_eq!(v, ["foo", "bar", "baz"]);
+	}
+
+	#[test]
+	fn deduplicate_07() {
+		let mut v: Vec<char> = "mississippi".chars().collect();
+
+		deduplicate(&mut v);
+		assert_eq!(v, ['m', 'i', 's', 'p']);
+	}
+
+	#[test]
+	fn deduplicate_hashed_00() {
+		let mut v: Vec<i32> = vec![];
+
+		deduplicate_hashed(&mut v);
+		assert_eq!(v, []);
+	}
+
+	#[test]
+	fn deduplicate_hashed_01() {
+		let mut v: Vec<i32> = vec![4, 3, 4, 2, 3, 2, 1];
+
+		deduplicate_hashed(&mut v);
+		assert_eq!(v, [4, 3, 2, 1]);
+	}
+
+	#[test]
+	fn deduplicate_hashed_02() {
+		let mut v: Vec<i32> = vec![i32::MIN, i32::MAX, 0, 0, 0, 7, i32::MAX, i32::MIN];
+
+		deduplicate_hashed(&mut v);
+		assert_eq!(v, [i32::MIN, i32::MAX, 0, 7]);
+	}
+
+	#[test]
+	fn deduplicate_hashed_03() {
+		let mut v: Vec<String> = ["foo", "bar", "foo", "baz", "bar"].map(String::from).to_vec();
+
+		deduplicate_hashed(&mut v);
+		assert_eq!(v, ["foo", "bar", "baz"]);
+	}
+
+	#[test]
+	fn deduplicate_hashed_04() {
+		let mut v: Vec<char> = "mississippi".chars().collect();
+
+		deduplicate_hashed(&mut v);
+		assert_eq!(v, ['m', 'i', 's', 'p']);
+	}
+
+	#[test]
+	fn deduplicate_sorted_00() {
+		let mut v: Vec<i32> = vec![];
+
+		deduplicate_sorted(&mut v);
+		assert_eq!(v, []);
+	}
+
+	#[test]
+	fn deduplicate_sorted_01() {
+		let mut v: Vec<i32> = vec![1, 1, 2, 3, 3, 3, 4];
+
+		deduplicate_sorted(&mut v);
+		assert_eq!(v, [1, 2, 3, 4]);
+	}
+
+	#[test]
+	fn deduplicate_sorted_02() {
+		let mut v: Vec<i32> = vec![42];
+
+		deduplicate_sorted(&mut v);
+		assert_eq!(v, [42]);
+	}
+
+	#[test]
+	fn deduplicate_sorted_03() {
+		let mut v: Vec<i32> = vec![i32::MIN, i32::MIN, -1, 0, 0, i32::MAX, i32::MAX];
+
+		deduplicate_sorted(&mut v);
+		assert_eq!(v, [i32::MIN, -1, 0, i32::MAX]);
+	}
+
+	#[test]
+	fn deduplicate_sorted_04() {
+		let mut v: Vec<i32> = vec![7, 7, 7, 7];
+
+		deduplicate_sorted(&mut v);
+		assert_eq!(v, [7]);
+	}
+
+	#[test]
+	fn deduplicate_sorted_05() {
+		let mut v: Vec<i32> = vec![3, 1, 1, 3, 2, 2, 3];
+
+		deduplicate_sorted(&mut v);
+		assert_eq!(v, [3, 1, 3, 2, 3]);
+	}
+
+	#[test]
+	fn running_product_00() {
+		let v: [i64; 0] = [];
+
+		assert_eq!(running_product(&v), []);
+	}
+
+	#[test]
+	fn running_product_01() {
+		assert_eq!(running_product(&[1, 2, 3, 4]), [1, 2, 6, 24]);
+	}
+
+	#[test]
+	fn running_product_02() {
+		assert_eq!(running_product(&[-3, 0, 5]), [-3, 0, 0]);
+	}
+
+	#[test]
+	fn running_product_03() {
+		assert_eq!(
+			running_product(&[i64::MAX, i64::MAX]),
+			[i64::MAX as i128, i64::MAX as i128 * i64::MAX as i128]
+		);
+	}
+
+	#[test]
+	fn running_product_04() {
+		assert_eq!(
+			running_product(&[i64::MIN, i64::MIN, -2]),
+			[i64::MIN as i128, 1 << 126, i128::MIN]
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "Product overflow")]
+	fn running_product_05() {
+		running_product(&[i64::MIN, i64::MIN, 2]);
+	}
+
+	#[test]
+	#[should_panic(expected = "Product overflow")]
+	fn running_product_06() {
+		running_product(&[i64::MAX; 3]);
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid chunk size")]
+	fn chunk_00() {
+		chunk(&[1, 2, 3], 0);
+	}
+
+	#[test]
+	fn chunk_01() {
+		let v: [i32; 0] = [];
+
+		assert_eq!(chunk(&v, 3), [] as [Vec<i32>; 0]);
+	}
+
+	#[test]
+	fn chunk_02() {
+		assert_eq!(chunk(&[1, 2, 3, 4, 5], 2), [vec![1, 2], vec![3, 4], vec![5]]);
+	}
+
+	#[test]
+	fn chunk_03() {
+		assert_eq!(chunk(&[1, 2, 3, 4], 2), [vec![1, 2], vec![3, 4]]);
+	}
+
+	#[test]
+	fn chunk_04() {
+		assert_eq!(chunk(&[1, 2, 3], 42), [vec![1, 2, 3]]);
+	}
+
+	#[test]
+	fn flatten_00() {
+		let vv: [Vec<i32>; 0] = [];
+
+		assert_eq!(flatten(&vv), []);
+	}
+
+	#[test]
+	fn flatten_01() {
+		assert_eq!(flatten(&[vec![], vec![]]), []);
+	}
+
+	#[test]
+	fn flatten_02() {
+		assert_eq!(flatten(&[vec![1, 2], vec![3]]), [1, 2, 3]);
+	}
+
+	#[test]
+	fn flatten_03() {
+		assert_eq!(flatten(&chunk(&[1, 2, 3, 4, 5], 2)), [1, 2, 3, 4, 5]);
+	}
+
+	#[test]
+	fn intersection_00() {
+		assert_eq!(intersection(&[], &[]), []);
+	}
+
+	#[test]
+	fn intersection_01() {
+		assert_eq!(intersection(&[1, 2, 3], &[]), []);
+	}
+
+	#[test]
+	fn intersection_02() {
+		assert_eq!(intersection(&[1, 2, 3, 2], &[2, 3, 4]), [2, 3]);
+	}
+
+	#[test]
+	fn intersection_03() {
+		assert_eq!(intersection(&[3, 1, 3, 2, 1], &[1, 1, 3]), [3, 1]);
+	}
+
+	#[test]
+	fn intersection_04() {
+		assert_eq!(intersection(&[1, 2, 3], &[4, 5, 6]), []);
+	}
+
+	#[test]
+	fn symmetric_difference_00() {
+		assert_eq!(symmetric_difference(&[], &[]), []);
+	}
+
+	#[test]
+	fn symmetric_difference_01() {
+		assert_eq!(symmetric_difference(&[1, 2, 3], &[2, 3, 4]), [1, 4]);
+	}
+
+	#[test]
+	fn symmetric_difference_02() {
+		assert_eq!(symmetric_difference(&[5, 1, 5], &[]), [5, 1]);
+	}
+
+	#[test]
+	fn symmetric_difference_03() {
+		assert_eq!(symmetric_difference(&[], &[7, 7, 6]), [7, 6]);
+	}
+
+	#[test]
+	fn symmetric_difference_04() {
+		assert_eq!(symmetric_difference(&[1, 2, 2], &[2, 1, 1]), []);
+	}
+
+	#[test]
+	fn symmetric_difference_05() {
+		assert_eq!(symmetric_difference(&[3, 9, 3, 8], &[8, 0, 4, 0]), [3, 9, 0, 4]);
+	}
 }
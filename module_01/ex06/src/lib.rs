@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+
 fn __are_digits_only(n: &[u8]) -> bool {
 	for &c in n {
 		if !c.is_ascii_digit() {
@@ -16,34 +18,22 @@ fn __most_significant_digit(n: &[u8]) -> usize {
 	return n.len() - 1;
 }
 
-/// Adds two big numbers.
-///
-/// ### Parameters
-/// * `a` - The first big number to add.
-/// * `b` - The second big number to add.
-///
-/// ### Return
-/// The sum of `a` and `b`.
-///
-/// ### Panic
-/// The input contains anything else than digits.
-///
-/// ### Example
-/// ```
-/// use ex06::big_add;
-///
-/// assert_eq!(
-/// 	big_add(
-/// 		b"000111222333444555666777888999101010111111",
-/// 		b"031"
-/// 	),
-/// 	b"111222333444555666777888999101010111142"
-/// );
-/// ```
-pub fn big_add(a: &[u8], b: &[u8]) -> Vec<u8> {
-	assert!(!a.is_empty() && !b.is_empty(), "Empty input");
-	assert!(__are_digits_only(a) && __are_digits_only(b), "Input contains non-digits");
+fn __split_sign(n: &[u8]) -> (bool, &[u8]) {
+	match n.first() {
+		Some(b'-') => (true, &n[1..]),
+		Some(b'+') => (false, &n[1..]),
+		_ => (false, n),
+	}
+}
+
+fn __cmp_magnitudes(a: &[u8], b: &[u8]) -> Ordering {
+	let a: &[u8] = &a[__most_significant_digit(a)..];
+	let b: &[u8] = &b[__most_significant_digit(b)..];
 
+	a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+fn __big_add_magnitudes(a: &[u8], b: &[u8]) -> Vec<u8> {
 	let mut sum: u8;
 	let mut carry: u8 = 0;
 	let mut result: Vec<u8> = Vec::new();
@@ -147,6 +137,160 @@ pub fn big_add(a: &[u8], b: &[u8]) -> Vec<u8> {
 	return result;
 }
 
+fn __big_sub_magnitudes(a: &[u8], b: &[u8]) -> Vec<u8> {
+	let mut digit: u8;
+	let mut borrow: u8 = 0;
+	let mut result: Vec<u8> = Vec::with_capacity(a.len());
+	let mut i_a: usize = a.len();
+	let mut i_b: usize = b.len();
+
+	while i_a > 0 {
+		i_a -= 1;
+		digit = a[i_a] - b'0';
+		let subtrahend: u8 = if i_b > 0 {
+			i_b -= 1;
+			b[i_b] - b'0' + borrow
+		} else {
+			borrow
+		};
+		if digit < subtrahend {
+			digit += 10;
+			borrow = 1;
+		} else {
+			borrow = 0;
+		}
+		result.push(digit - subtrahend + b'0');
+	}
+	while result.len() > 1 && result[result.len() - 1] == b'0' {
+		result.pop();
+	}
+	result.reverse();
+	result
+}
+
+/// Adds two big numbers.
+/// Each number may start with a `-` or a `+` sign.
+///
+/// ### Parameters
+/// * `a` - The first big number to add.
+/// * `b` - The second big number to add.
+///
+/// ### Return
+/// The sum of `a` and `b`, starting with a `-` sign if it is negative.
+///
+/// ### Panic
+/// The input contains anything else than digits, apart from the leading sign.
+///
+/// ### Example
+/// ```
+/// use ex06::big_add;
+///
+/// assert_eq!(
+/// 	big_add(
+/// 		b"000111222333444555666777888999101010111111",
+/// 		b"031"
+/// 	),
+/// 	b"111222333444555666777888999101010111142"
+/// );
+/// assert_eq!(big_add(b"-5", b"3"), b"-2");
+/// ```
+pub fn big_add(a: &[u8], b: &[u8]) -> Vec<u8> {
+	assert!(!a.is_empty() && !b.is_empty(), "Empty input");
+
+	let (is_negative_a, a): (bool, &[u8]) = __split_sign(a);
+	let (is_negative_b, b): (bool, &[u8]) = __split_sign(b);
+
+	assert!(!a.is_empty() && !b.is_empty(), "Empty input");
+	assert!(__are_digits_only(a) && __are_digits_only(b), "Input contains non-digits");
+
+	let (is_negative, mut result): (bool, Vec<u8>) = if is_negative_a == is_negative_b {
+		(is_negative_a, __big_add_magnitudes(a, b))
+	} else {
+		match __cmp_magnitudes(a, b) {
+			Ordering::Less => (is_negative_b, __big_sub_magnitudes(b, a)),
+			Ordering::Equal => (false, vec![b'0']),
+			Ordering::Greater => (is_negative_a, __big_sub_magnitudes(a, b)),
+		}
+	};
+
+	if is_negative && result != b"0" {
+		result.insert(0, b'-');
+	}
+	result
+}
+
+/// Multiplies two big numbers.
+///
+/// ### Parameters
+/// * `a` - The first big number to multiply.
+/// * `b` - The second big number to multiply.
+///
+/// ### Return
+/// The product of `a` and `b`.
+///
+/// ### Panic
+/// The input contains anything else than digits.
+///
+/// ### Example
+/// ```
+/// use ex06::big_mul;
+///
+/// assert_eq!(big_mul(b"000123", b"45"), b"5535");
+/// ```
+pub fn big_mul(a: &[u8], b: &[u8]) -> Vec<u8> {
+	assert!(!a.is_empty() && !b.is_empty(), "Empty input");
+	assert!(__are_digits_only(a) && __are_digits_only(b), "Input contains non-digits");
+
+	let a: &[u8] = &a[__most_significant_digit(a)..];
+	let b: &[u8] = &b[__most_significant_digit(b)..];
+	let mut digits: Vec<u32> = vec![0; a.len() + b.len()]; // least significant digit first
+
+	for (i, &digit_a) in a.iter().rev().enumerate() {
+		let mut carry: u32 = 0;
+
+		for (j, &digit_b) in b.iter().rev().enumerate() {
+			let product: u32 =
+				digits[i + j] + (digit_a - b'0') as u32 * (digit_b - b'0') as u32 + carry;
+
+			digits[i + j] = product % 10;
+			carry = product / 10;
+		}
+		digits[i + b.len()] += carry;
+	}
+	while digits.len() > 1 && digits[digits.len() - 1] == 0 {
+		digits.pop();
+	}
+	digits.iter().rev().map(|&digit: &u32| digit as u8 + b'0').collect()
+}
+
+/// Compares two big numbers.
+///
+/// ### Parameters
+/// * `a` - The first big number to compare.
+/// * `b` - The second big number to compare.
+///
+/// ### Return
+/// The ordering of `a` relatively to `b`.
+///
+/// ### Panic
+/// The input contains anything else than digits.
+///
+/// ### Example
+/// ```
+/// use std::cmp::Ordering;
+///
+/// use ex06::big_cmp;
+///
+/// assert_eq!(big_cmp(b"007", b"7"), Ordering::Equal);
+/// assert_eq!(big_cmp(b"10", b"9"), Ordering::Greater);
+/// ```
+pub fn big_cmp(a: &[u8], b: &[u8]) -> Ordering {
+	assert!(!a.is_empty() && !b.is_empty(), "Empty input");
+	assert!(__are_digits_only(a) && __are_digits_only(b), "Input contains non-digits");
+
+	__cmp_magnitudes(a, b)
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -235,4 +379,198 @@ mod tests {
 	fn big_add_15() {
 		assert_eq!(big_add(b"00000001234", b"00005678"), b"6912");
 	}
+
+	#[test]
+	fn big_add_16() {
+		assert_eq!(big_add(b"-5", b"3"), b"-2");
+	}
+
+	#[test]
+	fn big_add_17() {
+		assert_eq!(big_add(b"-5", b"5"), b"0");
+	}
+
+	#[test]
+	fn big_add_18() {
+		assert_eq!(big_add(b"5", b"-3"), b"2");
+	}
+
+	#[test]
+	fn big_add_19() {
+		assert_eq!(big_add(b"3", b"-5"), b"-2");
+	}
+
+	#[test]
+	fn big_add_20() {
+		assert_eq!(big_add(b"-5", b"-3"), b"-8");
+	}
+
+	#[test]
+	fn big_add_21() {
+		assert_eq!(big_add(b"+5", b"+3"), b"8");
+	}
+
+	#[test]
+	fn big_add_22() {
+		assert_eq!(big_add(b"-0", b"-0"), b"0");
+	}
+
+	#[test]
+	fn big_add_23() {
+		assert_eq!(big_add(b"-0", b"+000"), b"0");
+	}
+
+	#[test]
+	fn big_add_24() {
+		assert_eq!(
+			big_add(
+				b"100000000000000000000000000000000000000000000000000000000000000000000000000000000",
+				b"-1"
+			),
+			b"99999999999999999999999999999999999999999999999999999999999999999999999999999999"
+		);
+	}
+
+	#[test]
+	fn big_add_25() {
+		assert_eq!(big_add(b"-0001000", b"00999"), b"-1");
+	}
+
+	#[test]
+	#[should_panic(expected = "Empty input")]
+	fn big_add_26() {
+		big_add(b"-", b"1");
+	}
+
+	#[test]
+	#[should_panic(expected = "Input contains non-digits")]
+	fn big_add_27() {
+		big_add(b"1-2", b"1");
+	}
+
+	#[test]
+	#[should_panic(expected = "Input contains non-digits")]
+	fn big_add_28() {
+		big_add(b"1", b"--2");
+	}
+
+	#[test]
+	#[should_panic(expected = "Empty input")]
+	fn big_mul_00() {
+		big_mul(b"", b"0");
+	}
+
+	#[test]
+	#[should_panic(expected = "Empty input")]
+	fn big_mul_01() {
+		big_mul(b"0", b"");
+	}
+
+	#[test]
+	#[should_panic(expected = "Input contains non-digits")]
+	fn big_mul_02() {
+		big_mul(b"0", b"0x2a");
+	}
+
+	#[test]
+	#[should_panic(expected = "Input contains non-digits")]
+	fn big_mul_03() {
+		big_mul(b"0x2a", b"0");
+	}
+
+	#[test]
+	fn big_mul_04() {
+		assert_eq!(big_mul(b"0", b"0"), b"0");
+	}
+
+	#[test]
+	fn big_mul_05() {
+		assert_eq!(big_mul(b"000", b"123"), b"0");
+	}
+
+	#[test]
+	fn big_mul_06() {
+		assert_eq!(big_mul(b"123", b"000"), b"0");
+	}
+
+	#[test]
+	fn big_mul_07() {
+		assert_eq!(big_mul(b"1", b"42"), b"42");
+	}
+
+	#[test]
+	fn big_mul_08() {
+		assert_eq!(big_mul(b"9", b"9"), b"81");
+	}
+
+	#[test]
+	fn big_mul_09() {
+		assert_eq!(big_mul(b"123456789", b"987654321000"), b"121932631112635269000");
+	}
+
+	#[test]
+	fn big_mul_10() {
+		assert_eq!(big_mul(b"987654321000", b"00123456789"), b"121932631112635269000");
+	}
+
+	#[test]
+	fn big_mul_11() {
+		assert_eq!(
+			big_mul(b"99999999999999999999", b"99999999999999999999"),
+			b"9999999999999999999800000000000000000001"
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "Empty input")]
+	fn big_cmp_00() {
+		big_cmp(b"", b"0");
+	}
+
+	#[test]
+	#[should_panic(expected = "Input contains non-digits")]
+	fn big_cmp_01() {
+		big_cmp(b"0x2a", b"0");
+	}
+
+	#[test]
+	#[should_panic(expected = "Input contains non-digits")]
+	fn big_cmp_02() {
+		big_cmp(b"1", b"-1");
+	}
+
+	#[test]
+	fn big_cmp_03() {
+		assert_eq!(big_cmp(b"007", b"7"), Ordering::Equal);
+	}
+
+	#[test]
+	fn big_cmp_04() {
+		assert_eq!(big_cmp(b"0", b"0000"), Ordering::Equal);
+	}
+
+	#[test]
+	fn big_cmp_05() {
+		assert_eq!(big_cmp(b"10", b"9"), Ordering::Greater);
+	}
+
+	#[test]
+	fn big_cmp_06() {
+		assert_eq!(big_cmp(b"9", b"10"), Ordering::Less);
+	}
+
+	#[test]
+	fn big_cmp_07() {
+		assert_eq!(big_cmp(b"000123", b"124"), Ordering::Less);
+	}
+
+	#[test]
+	fn big_cmp_08() {
+		assert_eq!(big_cmp(b"99999999999999999999", b"0100000000000000000000"), Ordering::Less);
+	}
+
+	#[test]
+	fn big_cmp_09() {
+		assert_eq!(big_cmp(b"54321", b"54312"), Ordering::Greater);
+	}
 }
@@ -1,3 +1,6 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+#[derive(Debug, PartialEq)]
 pub struct Point {
 	pub x: f32,
 	pub y: f32,
@@ -107,6 +110,245 @@ impl Point {
 		self.x += dx;
 		self.y += dy;
 	}
+
+	/// Calculates the point that is halfway between the point and another given point.
+	///
+	/// ### Parameters
+	/// * `other` - The other point to calculate the midpoint with.
+	///
+	/// ### Return
+	/// The midpoint of the two points.
+	/// If any coordinate of the two points is NaN, both coordinates of the midpoint are NaN.
+	///
+	/// ### Example
+	/// ```
+	/// use ex01::Point;
+	///
+	/// let p0: Point = Point::new(1.0, 2.0);
+	/// let p1: Point = Point::new(2.0, -1.0);
+	/// let midpoint: Point = p0.midpoint(&p1);
+	///
+	/// assert_eq!(midpoint.x, 1.5);
+	/// assert_eq!(midpoint.y, 0.5);
+	/// ```
+	pub fn midpoint(self: &Self, other: &Self) -> Self {
+		if self.x.is_nan() || self.y.is_nan() || other.x.is_nan() || other.y.is_nan() {
+			return Self::new(f32::NAN, f32::NAN);
+		}
+
+		Self::new(
+			((self.x as f64 + other.x as f64) / 2.0) as f32,
+			((self.y as f64 + other.y as f64) / 2.0) as f32,
+		)
+	}
+
+	/// Linearly interpolates between the point and another given point.
+	///
+	/// ### Parameters
+	/// * `other` - The other point to interpolate with.
+	/// * `t` - The interpolation factor, 0 giving the point itself and 1 giving `other`.
+	///
+	/// ### Return
+	/// The interpolated point.
+	/// If `t` or any coordinate of the two points is NaN, both coordinates of the result are NaN.
+	///
+	/// ### Example
+	/// ```
+	/// use ex01::Point;
+	///
+	/// let p0: Point = Point::new(1.0, 2.0);
+	/// let p1: Point = Point::new(5.0, -2.0);
+	/// let p: Point = p0.lerp(&p1, 0.25);
+	///
+	/// assert_eq!(p.x, 2.0);
+	/// assert_eq!(p.y, 1.0);
+	/// ```
+	pub fn lerp(self: &Self, other: &Self, t: f32) -> Self {
+		if self.x.is_nan() || self.y.is_nan() || other.x.is_nan() || other.y.is_nan() || t.is_nan()
+		{
+			return Self::new(f32::NAN, f32::NAN);
+		}
+
+		let t: f64 = t as f64;
+
+		Self::new(
+			((1.0 - t) * self.x as f64 + t * other.x as f64) as f32,
+			((1.0 - t) * self.y as f64 + t * other.y as f64) as f32,
+		)
+	}
+}
+
+impl Add for Point {
+	type Output = Self;
+
+	#[inline(always)]
+	fn add(self: Self, rhs: Self) -> Self::Output {
+		Self::new(self.x + rhs.x, self.y + rhs.y)
+	}
+}
+
+impl Sub for Point {
+	type Output = Self;
+
+	#[inline(always)]
+	fn sub(self: Self, rhs: Self) -> Self::Output {
+		Self::new(self.x - rhs.x, self.y - rhs.y)
+	}
+}
+
+impl AddAssign for Point {
+	#[inline(always)]
+	fn add_assign(self: &mut Self, rhs: Self) {
+		self.x += rhs.x;
+		self.y += rhs.y;
+	}
+}
+
+impl SubAssign for Point {
+	#[inline(always)]
+	fn sub_assign(self: &mut Self, rhs: Self) {
+		self.x -= rhs.x;
+		self.y -= rhs.y;
+	}
+}
+
+/// Checks whether three points lie on a same line.
+/// The check is done by comparing the cross product of `(b - a)` and `(c - a)` to zero,
+/// with a tolerance relative to the distances between the points.
+///
+/// ### Parameters
+/// * `a` - The first point.
+/// * `b` - The second point.
+/// * `c` - The third point.
+///
+/// ### Return
+/// * `true` - The three points are collinear.
+/// * `false` - The three points are not collinear, or any coordinate is NaN.
+///
+/// ### Example
+/// ```
+/// use ex01::{are_collinear, Point};
+///
+/// assert!(are_collinear(&Point::new(0.0, 0.0), &Point::new(1.0, 1.0), &Point::new(2.0, 2.0)));
+/// assert!(!are_collinear(&Point::new(0.0, 0.0), &Point::new(1.0, 1.0), &Point::new(2.0, 3.0)));
+/// ```
+pub fn are_collinear(a: &Point, b: &Point, c: &Point) -> bool {
+	let abx: f64 = b.x as f64 - a.x as f64;
+	let aby: f64 = b.y as f64 - a.y as f64;
+	let acx: f64 = c.x as f64 - a.x as f64;
+	let acy: f64 = c.y as f64 - a.y as f64;
+	let cross: f64 = abx * acy - aby * acx;
+	let scale: f64 = abx.hypot(aby) * acx.hypot(acy);
+
+	cross.abs() <= f32::EPSILON as f64 * scale
+}
+
+/// Calculates the perimeter of a polygon.
+///
+/// ### Parameters
+/// * `points` - The vertices of the polygon, in order.
+///
+/// ### Return
+/// The sum of the distances between every two consecutive points,
+/// including the distance between the last point and the first one.
+/// If there are less than 2 points, 0 is returned.
+///
+/// ### Example
+/// ```
+/// use ex01::{perimeter, Point};
+///
+/// let square: [Point; 4] = [
+/// 	Point::new(0.0, 0.0),
+/// 	Point::new(1.0, 0.0),
+/// 	Point::new(1.0, 1.0),
+/// 	Point::new(0.0, 1.0),
+/// ];
+///
+/// assert_eq!(perimeter(&square), 4.0);
+/// ```
+pub fn perimeter(points: &[Point]) -> f32 {
+	if points.len() < 2 {
+		return 0.0;
+	}
+
+	let closing: f32 = points[points.len() - 1].distance(&points[0]);
+
+	points.windows(2).map(|pair: &[Point]| pair[0].distance(&pair[1])).sum::<f32>() + closing
+}
+
+/// Calculates the area of a polygon, using the shoelace formula.
+///
+/// ### Parameters
+/// * `points` - The vertices of the polygon, in order.
+///
+/// ### Return
+/// The absolute area of the polygon.
+/// If there are less than 3 points, 0 is returned.
+///
+/// ### Example
+/// ```
+/// use ex01::{polygon_area, Point};
+///
+/// let square: [Point; 4] = [
+/// 	Point::new(0.0, 0.0),
+/// 	Point::new(1.0, 0.0),
+/// 	Point::new(1.0, 1.0),
+/// 	Point::new(0.0, 1.0),
+/// ];
+///
+/// assert_eq!(polygon_area(&square), 1.0);
+/// ```
+pub fn polygon_area(points: &[Point]) -> f32 {
+	if points.len() < 3 {
+		return 0.0;
+	}
+
+	let mut twice_signed_area: f64 = 0.0;
+
+	for i in 0..points.len() {
+		let p0: &Point = &points[i];
+		let p1: &Point = &points[(i + 1) % points.len()];
+
+		twice_signed_area += p0.x as f64 * p1.y as f64 - p1.x as f64 * p0.y as f64;
+	}
+
+	(twice_signed_area.abs() / 2.0) as f32
+}
+
+/// Converts an angle from degrees to radians.
+///
+/// ### Parameters
+/// * `deg` - The angle to convert, in degrees.
+///
+/// ### Return
+/// The converted angle, in radians.
+///
+/// ### Example
+/// ```
+/// use ex01::to_radians;
+///
+/// assert_eq!(to_radians(180.0), std::f32::consts::PI);
+/// ```
+pub fn to_radians(deg: f32) -> f32 {
+	(deg as f64).to_radians() as f32
+}
+
+/// Converts an angle from radians to degrees.
+///
+/// ### Parameters
+/// * `rad` - The angle to convert, in radians.
+///
+/// ### Return
+/// The converted angle, in degrees.
+///
+/// ### Example
+/// ```
+/// use ex01::to_degrees;
+///
+/// assert_eq!(to_degrees(std::f32::consts::PI), 180.0);
+/// ```
+pub fn to_degrees(rad: f32) -> f32 {
+	(rad as f64).to_degrees() as f32
 }
 
 #[cfg(test)]
@@ -504,4 +746,413 @@ mod tests {
 
 		assert_eq!(f32::is_nan(p0.distance(&p1)), true);
 	}
+
+	#[test]
+	fn point_midpoint_00() {
+		let p0: Point = Point::new(0.0, 0.0);
+		let p1: Point = Point::new(0.0, 0.0);
+		let p: Point = p0.midpoint(&p1);
+
+		assert_eq!(p.x, 0.0);
+		assert_eq!(p.y, 0.0);
+	}
+
+	#[test]
+	fn point_midpoint_01() {
+		let p0: Point = Point::new(-3.0, 4.0);
+		let p1: Point = Point::new(5.0, 1.0);
+		let p: Point = p0.midpoint(&p1);
+
+		assert_eq!(p.x, 1.0);
+		assert_eq!(p.y, 2.5);
+	}
+
+	#[test]
+	fn point_midpoint_02() {
+		let p0: Point = Point::new(f32::MAX, f32::MIN);
+		let p1: Point = Point::new(f32::MAX, f32::MIN);
+		let p: Point = p0.midpoint(&p1);
+
+		assert_eq!(p.x, f32::MAX);
+		assert_eq!(p.y, f32::MIN);
+	}
+
+	#[test]
+	fn point_midpoint_03() {
+		let p0: Point = Point::new(0.0, 0.0);
+		let p1: Point = Point::new(f32::NAN, 0.0);
+		let p: Point = p0.midpoint(&p1);
+
+		assert_eq!(f32::is_nan(p.x), true);
+		assert_eq!(f32::is_nan(p.y), true);
+	}
+
+	#[test]
+	fn point_lerp_00() {
+		let p0: Point = Point::new(0.1, -0.7);
+		let p1: Point = Point::new(3.3, 1e30);
+		let p: Point = p0.lerp(&p1, 0.0);
+
+		assert_eq!(p.x, p0.x);
+		assert_eq!(p.y, p0.y);
+	}
+
+	#[test]
+	fn point_lerp_01() {
+		let p0: Point = Point::new(0.1, -0.7);
+		let p1: Point = Point::new(3.3, 1e30);
+		let p: Point = p0.lerp(&p1, 1.0);
+
+		assert_eq!(p.x, p1.x);
+		assert_eq!(p.y, p1.y);
+	}
+
+	#[test]
+	fn point_lerp_02() {
+		let p0: Point = Point::new(-1.3, 42.0);
+		let p1: Point = Point::new(7.9, -0.1);
+		let p: Point = p0.lerp(&p1, 0.5);
+		let midpoint: Point = p0.midpoint(&p1);
+
+		assert_eq!(p.x, midpoint.x);
+		assert_eq!(p.y, midpoint.y);
+	}
+
+	#[test]
+	fn point_lerp_03() {
+		let p0: Point = Point::new(0.0, 0.0);
+		let p1: Point = Point::new(2.0, -4.0);
+		let p: Point = p0.lerp(&p1, 2.0);
+
+		assert_eq!(p.x, 4.0);
+		assert_eq!(p.y, -8.0);
+	}
+
+	#[test]
+	fn point_lerp_04() {
+		let p0: Point = Point::new(0.0, 0.0);
+		let p1: Point = Point::new(2.0, -4.0);
+		let p: Point = p0.lerp(&p1, f32::NAN);
+
+		assert_eq!(f32::is_nan(p.x), true);
+		assert_eq!(f32::is_nan(p.y), true);
+	}
+
+	#[test]
+	fn point_lerp_05() {
+		let p0: Point = Point::new(0.0, f32::NAN);
+		let p1: Point = Point::new(2.0, -4.0);
+		let p: Point = p0.lerp(&p1, 0.5);
+
+		assert_eq!(f32::is_nan(p.x), true);
+		assert_eq!(f32::is_nan(p.y), true);
+	}
+
+	#[test]
+	fn point_add_00() {
+		assert_eq!(Point::new(1.0, 2.0) + Point::new(3.0, 4.0), Point::new(4.0, 6.0));
+	}
+
+	#[test]
+	fn point_add_01() {
+		assert_eq!(Point::new(-1.5, 2.0) + Point::zero(), Point::new(-1.5, 2.0));
+	}
+
+	#[test]
+	fn point_add_02() {
+		let p: Point = Point::new(f32::MAX, f32::INFINITY) + Point::new(f32::MAX, 1.0);
+
+		assert_eq!(p, Point::new(f32::INFINITY, f32::INFINITY));
+	}
+
+	#[test]
+	fn point_sub_00() {
+		assert_eq!(Point::new(4.0, 6.0) - Point::new(3.0, 4.0), Point::new(1.0, 2.0));
+	}
+
+	#[test]
+	fn point_sub_01() {
+		assert_eq!(Point::zero() - Point::new(-1.5, 2.0), Point::new(1.5, -2.0));
+	}
+
+	#[test]
+	fn point_sub_02() {
+		let p: Point = Point::new(f32::NAN, 1.0) - Point::new(0.0, 1.0);
+
+		assert_eq!(f32::is_nan(p.x), true);
+		assert_eq!(p.y, 0.0);
+	}
+
+	#[test]
+	fn point_add_assign_00() {
+		let mut p: Point = Point::new(1.0, 2.0);
+
+		p += Point::new(3.0, 4.0);
+		assert_eq!(p, Point::new(4.0, 6.0));
+	}
+
+	#[test]
+	fn point_add_assign_01() {
+		let mut p: Point = Point::new(1.0, 2.0);
+
+		p += Point::new(-2.0, 1.0);
+		p += Point::new(-2.0, 1.0);
+		assert_eq!(p, Point::new(-3.0, 4.0));
+	}
+
+	#[test]
+	fn point_sub_assign_00() {
+		let mut p: Point = Point::new(4.0, 6.0);
+
+		p -= Point::new(3.0, 4.0);
+		assert_eq!(p, Point::new(1.0, 2.0));
+	}
+
+	#[test]
+	fn point_sub_assign_01() {
+		let mut p: Point = Point::new(1.0, 2.0);
+
+		p -= Point::new(1.0, 2.0);
+		assert_eq!(p, Point::zero());
+	}
+
+	#[test]
+	fn are_collinear_00() {
+		let a: Point = Point::new(0.0, 0.0);
+		let b: Point = Point::new(1.0, 1.0);
+		let c: Point = Point::new(2.0, 2.0);
+
+		assert_eq!(are_collinear(&a, &b, &c), true);
+	}
+
+	#[test]
+	fn are_collinear_01() {
+		let a: Point = Point::new(0.0, 0.0);
+		let b: Point = Point::new(1.0, 1.0);
+		let c: Point = Point::new(2.0, 3.0);
+
+		assert_eq!(are_collinear(&a, &b, &c), false);
+	}
+
+	#[test]
+	fn are_collinear_02() {
+		let a: Point = Point::new(1.0, 2.0);
+		let b: Point = Point::new(1.0, 2.0);
+		let c: Point = Point::new(1.0, 2.0);
+
+		assert_eq!(are_collinear(&a, &b, &c), true);
+	}
+
+	#[test]
+	fn are_collinear_03() {
+		let a: Point = Point::new(-3.0, 5.0);
+		let b: Point = Point::new(7.0, 5.0);
+		let c: Point = Point::new(-42.0, 5.0);
+
+		assert_eq!(are_collinear(&a, &b, &c), true);
+	}
+
+	#[test]
+	fn are_collinear_04() {
+		let a: Point = Point::new(0.1, 0.2);
+		let b: Point = Point::new(0.3, 0.6);
+		let c: Point = Point::new(0.7, 1.4);
+
+		assert_eq!(are_collinear(&a, &b, &c), true);
+	}
+
+	#[test]
+	fn are_collinear_05() {
+		let a: Point = Point::new(0.0, 0.0);
+		let b: Point = Point::new(1000000.0, 1000000.0);
+		let c: Point = Point::new(2000000.0, 2000001.0);
+
+		assert_eq!(are_collinear(&a, &b, &c), false);
+	}
+
+	#[test]
+	fn are_collinear_06() {
+		let a: Point = Point::new(0.0, 0.0);
+		let b: Point = Point::new(f32::NAN, 1.0);
+		let c: Point = Point::new(2.0, 2.0);
+
+		assert_eq!(are_collinear(&a, &b, &c), false);
+	}
+
+	#[test]
+	fn are_collinear_07() {
+		let a: Point = Point::new(f32::NAN, f32::NAN);
+		let b: Point = Point::new(f32::NAN, f32::NAN);
+		let c: Point = Point::new(f32::NAN, f32::NAN);
+
+		assert_eq!(are_collinear(&a, &b, &c), false);
+	}
+
+	#[test]
+	fn perimeter_00() {
+		let points: [Point; 0] = [];
+
+		assert_eq!(perimeter(&points), 0.0);
+	}
+
+	#[test]
+	fn perimeter_01() {
+		let points: [Point; 1] = [Point::new(42.0, 21.0)];
+
+		assert_eq!(perimeter(&points), 0.0);
+	}
+
+	#[test]
+	fn perimeter_02() {
+		let points: [Point; 2] = [Point::new(0.0, 0.0), Point::new(3.0, 4.0)];
+
+		assert_eq!(perimeter(&points), 10.0);
+	}
+
+	#[test]
+	fn perimeter_03() {
+		let points: [Point; 4] = [
+			Point::new(0.0, 0.0),
+			Point::new(1.0, 0.0),
+			Point::new(1.0, 1.0),
+			Point::new(0.0, 1.0),
+		];
+
+		assert_eq!(perimeter(&points), 4.0);
+	}
+
+	#[test]
+	fn perimeter_04() {
+		let points: [Point; 3] =
+			[Point::new(-1.0, -1.0), Point::new(2.0, 3.0), Point::new(2.0, -1.0)];
+
+		assert_eq!(perimeter(&points), 12.0);
+	}
+
+	#[test]
+	fn perimeter_05() {
+		let points: [Point; 3] =
+			[Point::new(0.0, 0.0), Point::new(f32::NAN, 1.0), Point::new(2.0, 2.0)];
+
+		assert_eq!(f32::is_nan(perimeter(&points)), true);
+	}
+
+	#[test]
+	fn polygon_area_00() {
+		let points: [Point; 0] = [];
+
+		assert_eq!(polygon_area(&points), 0.0);
+	}
+
+	#[test]
+	fn polygon_area_01() {
+		let points: [Point; 2] = [Point::new(0.0, 0.0), Point::new(3.0, 4.0)];
+
+		assert_eq!(polygon_area(&points), 0.0);
+	}
+
+	#[test]
+	fn polygon_area_02() {
+		let points: [Point; 4] = [
+			Point::new(0.0, 0.0),
+			Point::new(1.0, 0.0),
+			Point::new(1.0, 1.0),
+			Point::new(0.0, 1.0),
+		];
+
+		assert_eq!(polygon_area(&points), 1.0);
+	}
+
+	#[test]
+	fn polygon_area_03() {
+		let points: [Point; 4] = [
+			Point::new(0.0, 1.0),
+			Point::new(1.0, 1.0),
+			Point::new(1.0, 0.0),
+			Point::new(0.0, 0.0),
+		];
+
+		assert_eq!(polygon_area(&points), 1.0);
+	}
+
+	#[test]
+	fn polygon_area_04() {
+		let points: [Point; 3] =
+			[Point::new(-1.0, -1.0), Point::new(2.0, 3.0), Point::new(2.0, -1.0)];
+
+		assert_eq!(polygon_area(&points), 6.0);
+	}
+
+	#[test]
+	fn polygon_area_05() {
+		let points: [Point; 3] = [Point::new(0.0, 0.0), Point::new(1.0, 1.0), Point::new(2.0, 2.0)];
+
+		assert_eq!(polygon_area(&points), 0.0);
+	}
+
+	#[test]
+	fn polygon_area_06() {
+		let points: [Point; 6] = [
+			Point::new(0.0, 0.0),
+			Point::new(4.0, 0.0),
+			Point::new(4.0, 3.0),
+			Point::new(2.0, 1.0),
+			Point::new(0.0, 3.0),
+			Point::new(-1.0, 1.5),
+		];
+
+		assert_eq!(polygon_area(&points), 9.5);
+	}
+
+	#[test]
+	fn to_radians_00() {
+		assert_eq!(to_radians(0.0), 0.0);
+	}
+
+	#[test]
+	fn to_radians_01() {
+		assert_eq!(to_radians(180.0), std::f32::consts::PI);
+	}
+
+	#[test]
+	fn to_radians_02() {
+		assert_eq!(to_radians(-90.0), -std::f32::consts::FRAC_PI_2);
+	}
+
+	#[test]
+	fn to_radians_03() {
+		assert!((to_radians(45.0) - std::f32::consts::FRAC_PI_4).abs() <= f32::EPSILON);
+	}
+
+	#[test]
+	fn to_radians_04() {
+		assert_eq!(f32::is_nan(to_radians(f32::NAN)), true);
+	}
+
+	#[test]
+	fn to_degrees_00() {
+		assert_eq!(to_degrees(0.0), 0.0);
+	}
+
+	#[test]
+	fn to_degrees_01() {
+		assert_eq!(to_degrees(std::f32::consts::PI), 180.0);
+	}
+
+	#[test]
+	fn to_degrees_02() {
+		assert_eq!(to_degrees(-std::f32::consts::FRAC_PI_2), -90.0);
+	}
+
+	#[test]
+	fn to_degrees_03() {
+		for deg in [-720.0, -135.0, 1.0, 30.0, 60.0, 359.0] {
+			assert!((to_degrees(to_radians(deg)) - deg).abs() <= deg.abs() * f32::EPSILON);
+		}
+	}
+
+	#[test]
+	fn to_degrees_04() {
+		assert_eq!(f32::is_nan(to_degrees(f32::NAN)), true);
+	}
 }
@@ -1,3 +1,6 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
 #[derive(Debug, PartialEq)]
 pub enum PizzaStatus {
 	Ordered,
@@ -7,6 +10,10 @@ pub enum PizzaStatus {
 	Delivered,
 }
 
+/// The error returned when a string does not name a pizza status.
+#[derive(Debug, PartialEq)]
+pub struct ParsePizzaStatusError;
+
 impl PizzaStatus {
 	/// Predicts the status of a pizza that was ordered days ago.
 	///
@@ -34,6 +41,29 @@ impl PizzaStatus {
 		}
 	}
 
+	/// Gets the range of days that map to the current status,
+	/// i.e. the inverse of `from_delivery_time`.
+	///
+	/// ### Return
+	/// The inclusive range of `ordered_days_ago` values that are predicted as the current status.
+	///
+	/// ### Example
+	/// ```
+	/// use ex02::PizzaStatus;
+	///
+	/// assert_eq!(PizzaStatus::Cooking.day_range(), 2..=6);
+	/// assert_eq!(PizzaStatus::Delivered.day_range(), 17..=u32::MAX);
+	/// ```
+	pub fn day_range(self: &Self) -> std::ops::RangeInclusive<u32> {
+		match self {
+			PizzaStatus::Ordered => 0..=1,
+			PizzaStatus::Cooking => 2..=6,
+			PizzaStatus::Cooked => 7..=9,
+			PizzaStatus::Delivering => 10..=16,
+			PizzaStatus::Delivered => 17..=u32::MAX,
+		}
+	}
+
 	/// Estimates the time before a pizza is delivered, in days.
 	/// Always the worst case is returned.
 	///
@@ -57,6 +87,136 @@ impl PizzaStatus {
 			PizzaStatus::Delivered => 0,
 		}
 	}
+
+	/// Predicts the status of a pizza that was ordered days ago,
+	/// along with the estimated time before it is delivered, in days.
+	///
+	/// ### Parameters
+	/// * `ordered_days_ago` - The days number reprensenting how long ago the pizza was ordered.
+	///
+	/// ### Return
+	/// A tuple containing the predicted status and its estimated time before delivery, in days.
+	///
+	/// ### Example
+	/// ```
+	/// use ex02::PizzaStatus;
+	///
+	/// assert_eq!(PizzaStatus::status_and_eta(0), (PizzaStatus::Ordered, 17));
+	/// ```
+	pub fn status_and_eta(ordered_days_ago: u32) -> (Self, u32) {
+		let status: Self = Self::from_delivery_time(ordered_days_ago);
+		let eta: u32 = status.get_delivery_time_in_days();
+
+		(status, eta)
+	}
+
+	/// Gets the status that follows the current one in the lifecycle of a pizza.
+	///
+	/// ### Return
+	/// * `Some(PizzaStatus)` - The next status.
+	/// * `None` - The pizza has already been delivered.
+	///
+	/// ### Example
+	/// ```
+	/// use ex02::PizzaStatus;
+	///
+	/// assert_eq!(PizzaStatus::Ordered.next(), Some(PizzaStatus::Cooking));
+	/// assert_eq!(PizzaStatus::Delivered.next(), None);
+	/// ```
+	pub fn next(self: &Self) -> Option<Self> {
+		match self {
+			PizzaStatus::Ordered => Some(PizzaStatus::Cooking),
+			PizzaStatus::Cooking => Some(PizzaStatus::Cooked),
+			PizzaStatus::Cooked => Some(PizzaStatus::Delivering),
+			PizzaStatus::Delivering => Some(PizzaStatus::Delivered),
+			PizzaStatus::Delivered => None,
+		}
+	}
+
+	/// Estimates how far a pizza is in its lifecycle,
+	/// based on the time left before it is delivered.
+	///
+	/// ### Return
+	/// The progress of the pizza, as a percentage between 0 and 100.
+	///
+	/// ### Example
+	/// ```
+	/// use ex02::PizzaStatus;
+	///
+	/// assert_eq!(PizzaStatus::Ordered.progress_percentage(), 0);
+	/// assert_eq!(PizzaStatus::Delivered.progress_percentage(), 100);
+	/// ```
+	pub fn progress_percentage(self: &Self) -> u32 {
+		let total: u32 = PizzaStatus::Ordered.get_delivery_time_in_days();
+
+		100 - self.get_delivery_time_in_days() * 100 / total
+	}
+
+	/// Draws a textual progress bar, filled proportionally to the progress of a pizza.
+	///
+	/// ### Parameters
+	/// * `width` - The number of cells of the bar, not counting the surrounding brackets.
+	///
+	/// ### Return
+	/// The progress bar, where filled cells are drawn as `'#'` and empty ones as `'.'`.
+	///
+	/// ### Example
+	/// ```
+	/// use ex02::PizzaStatus;
+	///
+	/// assert_eq!(PizzaStatus::Cooked.progress_bar(10), "[####......]");
+	/// ```
+	pub fn progress_bar(self: &Self, width: usize) -> String {
+		let filled: usize = width * self.progress_percentage() as usize / 100;
+
+		format!("[{}{}]", "#".repeat(filled), ".".repeat(width - filled))
+	}
+}
+
+impl Display for PizzaStatus {
+	/// Formats the status as its lowercase name.
+	///
+	/// ### Example
+	/// ```
+	/// use ex02::PizzaStatus;
+	///
+	/// assert_eq!(PizzaStatus::Cooking.to_string(), "cooking");
+	/// ```
+	fn fmt(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
+		let name: &str = match self {
+			PizzaStatus::Ordered => "ordered",
+			PizzaStatus::Cooking => "cooking",
+			PizzaStatus::Cooked => "cooked",
+			PizzaStatus::Delivering => "delivering",
+			PizzaStatus::Delivered => "delivered",
+		};
+
+		write!(f, "{}", name)
+	}
+}
+
+impl FromStr for PizzaStatus {
+	type Err = ParsePizzaStatusError;
+
+	/// Parses a status from its name, ignoring ASCII case.
+	///
+	/// ### Example
+	/// ```
+	/// use ex02::{ParsePizzaStatusError, PizzaStatus};
+	///
+	/// assert_eq!("Cooking".parse::<PizzaStatus>(), Ok(PizzaStatus::Cooking));
+	/// assert_eq!("burnt".parse::<PizzaStatus>(), Err(ParsePizzaStatusError));
+	/// ```
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.to_ascii_lowercase().as_str() {
+			"ordered" => Ok(PizzaStatus::Ordered),
+			"cooking" => Ok(PizzaStatus::Cooking),
+			"cooked" => Ok(PizzaStatus::Cooked),
+			"delivering" => Ok(PizzaStatus::Delivering),
+			"delivered" => Ok(PizzaStatus::Delivered),
+			_ => Err(ParsePizzaStatusError),
+		}
+	}
 }
 
 #[cfg(test)]
@@ -224,6 +384,43 @@ mod tests {
 		assert_eq!(status, PizzaStatus::Delivered);
 	}
 
+	#[test]
+	fn day_range_00() {
+		assert_eq!(PizzaStatus::Ordered.day_range(), 0..=1);
+		assert_eq!(PizzaStatus::Cooking.day_range(), 2..=6);
+		assert_eq!(PizzaStatus::Cooked.day_range(), 7..=9);
+		assert_eq!(PizzaStatus::Delivering.day_range(), 10..=16);
+		assert_eq!(PizzaStatus::Delivered.day_range(), 17..=u32::MAX);
+	}
+
+	#[test]
+	fn day_range_01() {
+		for status in [
+			PizzaStatus::Ordered,
+			PizzaStatus::Cooking,
+			PizzaStatus::Cooked,
+			PizzaStatus::Delivering,
+			PizzaStatus::Delivered,
+		] {
+			let range: std::ops::RangeInclusive<u32> = status.day_range();
+
+			assert_eq!(PizzaStatus::from_delivery_time(*range.start()), status);
+			assert_eq!(PizzaStatus::from_delivery_time(*range.end()), status);
+		}
+	}
+
+	#[test]
+	fn day_range_02() {
+		let mut status: PizzaStatus = PizzaStatus::Ordered;
+
+		assert_eq!(*status.day_range().start(), 0);
+		while let Some(next) = status.next() {
+			assert_eq!(*next.day_range().start(), *status.day_range().end() + 1);
+			status = next;
+		}
+		assert_eq!(*status.day_range().end(), u32::MAX);
+	}
+
 	#[test]
 	fn get_delivery_time_in_days_ordered_00() {
 		let status: PizzaStatus = PizzaStatus::Ordered;
@@ -258,4 +455,227 @@ mod tests {
 
 		assert_eq!(status.get_delivery_time_in_days(), 0);
 	}
+
+	#[test]
+	fn status_and_eta_00() {
+		assert_eq!(PizzaStatus::status_and_eta(0), (PizzaStatus::Ordered, 17));
+	}
+
+	#[test]
+	fn status_and_eta_01() {
+		assert_eq!(PizzaStatus::status_and_eta(4), (PizzaStatus::Cooking, 15));
+	}
+
+	#[test]
+	fn status_and_eta_02() {
+		assert_eq!(PizzaStatus::status_and_eta(9), (PizzaStatus::Cooked, 10));
+	}
+
+	#[test]
+	fn status_and_eta_03() {
+		assert_eq!(PizzaStatus::status_and_eta(16), (PizzaStatus::Delivering, 7));
+	}
+
+	#[test]
+	fn status_and_eta_04() {
+		assert_eq!(PizzaStatus::status_and_eta(17), (PizzaStatus::Delivered, 0));
+	}
+
+	#[test]
+	fn status_and_eta_05() {
+		for ordered_days_ago in 0..32 {
+			let status: PizzaStatus = PizzaStatus::from_delivery_time(ordered_days_ago);
+			let eta: u32 = status.get_delivery_time_in_days();
+
+			assert_eq!(PizzaStatus::status_and_eta(ordered_days_ago), (status, eta));
+		}
+	}
+
+	#[test]
+	fn next_00() {
+		let status: PizzaStatus = PizzaStatus::Ordered;
+
+		assert_eq!(status.next(), Some(PizzaStatus::Cooking));
+	}
+
+	#[test]
+	fn next_01() {
+		let status: PizzaStatus = PizzaStatus::Cooking;
+
+		assert_eq!(status.next(), Some(PizzaStatus::Cooked));
+	}
+
+	#[test]
+	fn next_02() {
+		let status: PizzaStatus = PizzaStatus::Cooked;
+
+		assert_eq!(status.next(), Some(PizzaStatus::Delivering));
+	}
+
+	#[test]
+	fn next_03() {
+		let status: PizzaStatus = PizzaStatus::Delivering;
+
+		assert_eq!(status.next(), Some(PizzaStatus::Delivered));
+	}
+
+	#[test]
+	fn next_04() {
+		let status: PizzaStatus = PizzaStatus::Delivered;
+
+		assert_eq!(status.next(), None);
+	}
+
+	#[test]
+	fn next_05() {
+		let mut status: PizzaStatus = PizzaStatus::Ordered;
+		let mut steps: u32 = 0;
+
+		while let Some(next) = status.next() {
+			assert!(next.get_delivery_time_in_days() < status.get_delivery_time_in_days());
+			status = next;
+			steps += 1;
+		}
+		assert_eq!(status, PizzaStatus::Delivered);
+		assert_eq!(steps, 4);
+	}
+
+	#[test]
+	fn progress_percentage_00() {
+		assert_eq!(PizzaStatus::Ordered.progress_percentage(), 0);
+		assert_eq!(PizzaStatus::Cooking.progress_percentage(), 12);
+		assert_eq!(PizzaStatus::Cooked.progress_percentage(), 42);
+		assert_eq!(PizzaStatus::Delivering.progress_percentage(), 59);
+		assert_eq!(PizzaStatus::Delivered.progress_percentage(), 100);
+	}
+
+	#[test]
+	fn progress_percentage_01() {
+		let mut status: PizzaStatus = PizzaStatus::Ordered;
+
+		while let Some(next) = status.next() {
+			assert!(next.progress_percentage() > status.progress_percentage());
+			status = next;
+		}
+	}
+
+	#[test]
+	fn progress_bar_00() {
+		assert_eq!(PizzaStatus::Ordered.progress_bar(10), "[..........]");
+	}
+
+	#[test]
+	fn progress_bar_01() {
+		assert_eq!(PizzaStatus::Delivered.progress_bar(10), "[##########]");
+	}
+
+	#[test]
+	fn progress_bar_02() {
+		assert_eq!(PizzaStatus::Cooking.progress_bar(10), "[#.........]");
+		assert_eq!(PizzaStatus::Cooked.progress_bar(10), "[####......]");
+		assert_eq!(PizzaStatus::Delivering.progress_bar(10), "[#####.....]");
+	}
+
+	#[test]
+	fn progress_bar_03() {
+		assert_eq!(PizzaStatus::Ordered.progress_bar(0), "[]");
+		assert_eq!(PizzaStatus::Delivered.progress_bar(0), "[]");
+	}
+
+	#[test]
+	fn progress_bar_04() {
+		for width in 0..64 {
+			assert_eq!(PizzaStatus::Cooked.progress_bar(width).chars().count(), width + 2);
+		}
+	}
+
+	#[test]
+	fn display_00() {
+		let status: PizzaStatus = PizzaStatus::Ordered;
+
+		assert_eq!(status.to_string(), "ordered");
+	}
+
+	#[test]
+	fn display_01() {
+		let status: PizzaStatus = PizzaStatus::Cooking;
+
+		assert_eq!(status.to_string(), "cooking");
+	}
+
+	#[test]
+	fn display_02() {
+		let status: PizzaStatus = PizzaStatus::Cooked;
+
+		assert_eq!(status.to_string(), "cooked");
+	}
+
+	#[test]
+	fn display_03() {
+		let status: PizzaStatus = PizzaStatus::Delivering;
+
+		assert_eq!(status.to_string(), "delivering");
+	}
+
+	#[test]
+	fn display_04() {
+		let status: PizzaStatus = PizzaStatus::Delivered;
+
+		assert_eq!(status.to_string(), "delivered");
+	}
+
+	#[test]
+	fn from_str_00() {
+		let status: PizzaStatus = PizzaStatus::Ordered;
+
+		assert_eq!(status.to_string().parse::<PizzaStatus>(), Ok(status));
+	}
+
+	#[test]
+	fn from_str_01() {
+		let status: PizzaStatus = PizzaStatus::Cooking;
+
+		assert_eq!(status.to_string().parse::<PizzaStatus>(), Ok(status));
+	}
+
+	#[test]
+	fn from_str_02() {
+		let status: PizzaStatus = PizzaStatus::Cooked;
+
+		assert_eq!(status.to_string().parse::<PizzaStatus>(), Ok(status));
+	}
+
+	#[test]
+	fn from_str_03() {
+		let status: PizzaStatus = PizzaStatus::Delivering;
+
+		assert_eq!(status.to_string().parse::<PizzaStatus>(), Ok(status));
+	}
+
+	#[test]
+	fn from_str_04() {
+		let status: PizzaStatus = PizzaStatus::Delivered;
+
+		assert_eq!(status.to_string().parse::<PizzaStatus>(), Ok(status));
+	}
+
+	#[test]
+	fn from_str_05() {
+		assert_eq!("DeLiVeReD".parse::<PizzaStatus>(), Ok(PizzaStatus::Delivered));
+	}
+
+	#[test]
+	fn from_str_06() {
+		assert_eq!("burnt".parse::<PizzaStatus>(), Err(ParsePizzaStatusError));
+	}
+
+	#[test]
+	fn from_str_07() {
+		assert_eq!("".parse::<PizzaStatus>(), Err(ParsePizzaStatusError));
+	}
+
+	#[test]
+	fn from_str_08() {
+		assert_eq!(" cooking".parse::<PizzaStatus>(), Err(ParsePizzaStatusError));
+	}
 }
@@ -1,88 +1,77 @@
-#[derive(Clone, Copy, Debug, PartialEq)]
+use std::collections::hash_map::{Entry, HashMap};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct Color {
 	red: u8,
 	green: u8,
 	blue: u8,
 }
 
+/// The ways of measuring the distance between two colors.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Metric {
+	/// The squared euclidean distance between the RGB components.
+	Euclidean,
+	/// The squared euclidean distance between the RGB components,
+	/// weighted by their contribution to the luminance (30% red, 59% green, 11% blue),
+	/// and expressed in hundredths.
+	WeightedRgb,
+}
+
 impl Color {
-	/// Calculates the distance with another color.
+	/// Finds out the colors that can be obtained by adding one more color of a palette
+	/// to given canvases, and that have never been obtained before.
 	///
 	/// ### Parameters
-	/// * `other` - The color to calculate the distance with.
+	/// * `canvases` - The current colors of the canvases we are painting on.
+	/// * `palette` - The palette of colors to mix.
+	/// * `seen` - The colors that have already been obtained, each associated with the canvas
+	///   and the index of the palette color it was first obtained from, if any.
+	///   Updated with the new ones.
 	///
 	/// ### Return
-	/// The distance between the two colors.
-	fn distance(self: &Self, other: &Self) -> u32 {
-		let diff_red: i32 = self.red as i32 - other.red as i32;
-		let diff_green: i32 = self.green as i32 - other.green as i32;
-		let diff_blue: i32 = self.blue as i32 - other.blue as i32;
+	/// The newly obtained colors, in the order they were obtained.
+	fn mix_next_colors(
+		canvases: &[Self],
+		palette: &[(Self, u8)],
+		seen: &mut HashMap<Self, Option<(Self, usize)>>,
+	) -> Vec<Self> {
+		let mut next_colors: Vec<Self> = Vec::new();
 
-		(diff_red * diff_red + diff_green * diff_green + diff_blue * diff_blue) as u32
-	}
+		for canvas in canvases {
+			for (i, (color, opacity)) in palette.iter().enumerate() {
+				let mixed: Self = color.blend(canvas, *opacity);
 
-	/// Adds a color to the canvas, and returns the resulting color.
-	/// The canvas is assumed to be completly opaque.
-	///
-	/// ### Parameters
-	/// * `color` - The color to add to the canvas,
-	/// represented by a tuple containing the Color instance and its opacity.
-	///
-	/// ### Return
-	/// The resulting color.
-	fn mix_color_to_canvas(self: &Self, canvas: &Self, opacity: u8) -> Self {
-		#[inline(always)]
-		fn mix_component(a: u8, b: u8, opacity: u8) -> u8 {
-			return ((a as u16 * opacity as u16 + b as u16 * (255 - opacity) as u16)
-				/ u8::MAX as u16) as u8;
+				if let Entry::Vacant(entry) = seen.entry(mixed) {
+					entry.insert(Some((*canvas, i)));
+					next_colors.push(mixed);
+				}
+			}
 		}
 
-		Self::new(
-			mix_component(self.red, canvas.red, opacity),
-			mix_component(self.green, canvas.green, opacity),
-			mix_component(self.blue, canvas.blue, opacity),
-		)
+		next_colors
 	}
 
-	/// Finds out the mix of colors that results in the closest color to the calling instance,
-	/// using a well defined number of colors to mix.
+	/// Retraces the palette colors that have been mixed to obtain a given color.
 	///
 	/// ### Parameters
-	/// * `canvas` - The current color of the canvas we are painting on.
-	/// * `closest` - The closest resulting mixed color we found so far.
-	/// * `palette` - The palette of colors to mix.
-	/// * `number_of_colors_to_mix` - The remaining number of colors we must add to the mix.
+	/// * `color` - The obtained color to retrace.
+	/// * `seen` - The colors that have already been obtained, each associated with the canvas
+	///   and the index of the palette color it was first obtained from, if any.
 	///
 	/// ### Return
-	/// The resulting mixed color that is closest to the `self` color.
-	fn mix_recursively(
-		self: &Self,
-		canvas: &Self,
-		closest: &mut Self,
-		palette: &[(Self, u8)],
-		number_of_colors_to_mix: u32,
-	) -> Self {
-		if number_of_colors_to_mix == 0 {
-			return canvas.clone();
-		}
-
-		for i in 0..palette.len() {
-			let current: Self = self.mix_recursively(
-				&palette[i].0.mix_color_to_canvas(canvas, palette[i].1),
-				closest,
-				palette,
-				number_of_colors_to_mix - 1,
-			);
-
-			if current.distance(self) < closest.distance(self) {
-				if current == *self {
-					return current;
-				}
-				*closest = current;
-			}
+	/// The indices of the mixed palette colors, in the order they were mixed.
+	fn mix_trace(color: Self, seen: &HashMap<Self, Option<(Self, usize)>>) -> Vec<usize> {
+		let mut trace: Vec<usize> = Vec::new();
+		let mut current: Self = color;
+
+		while let Some(Some((canvas, i))) = seen.get(&current) {
+			trace.push(*i);
+			current = *canvas;
 		}
+		trace.reverse();
 
-		closest.clone()
+		trace
 	}
 
 	pub const RED: Self = Self::new(0xff, 0x00, 0x00);
@@ -113,6 +102,152 @@ impl Color {
 		Self { red, green, blue }
 	}
 
+	/// Creates a new Color instance from its hexadecimal representation.
+	///
+	/// ### Parameters
+	/// * `s` - The hexadecimal representation of the color, formatted as `#rrggbb` or `rrggbb`.
+	///   Both lowercase and uppercase digits are accepted.
+	///
+	/// ### Return
+	/// * `Some(Color)` - The newly created Color instance.
+	/// * `None` - `s` is not a valid hexadecimal representation of a color.
+	///
+	/// ### Example
+	/// ```
+	/// use ex05::Color;
+	///
+	/// assert_eq!(Color::from_hex("#FF0000"), Some(Color::RED));
+	/// assert_eq!(Color::from_hex("00ff00"), Some(Color::GREEN));
+	/// assert_eq!(Color::from_hex("#ff00"), None);
+	/// ```
+	pub fn from_hex(s: &str) -> Option<Self> {
+		let digits: &str = s.strip_prefix('#').unwrap_or(s);
+
+		if digits.len() != 6 || !digits.bytes().all(|c: u8| c.is_ascii_hexdigit()) {
+			return None;
+		}
+
+		let component = |i: usize| -> Option<u8> { u8::from_str_radix(&digits[i..i + 2], 16).ok() };
+
+		Some(Self::new(component(0)?, component(2)?, component(4)?))
+	}
+
+	/// Formats the color as its hexadecimal representation.
+	///
+	/// ### Return
+	/// The hexadecimal representation of the color, formatted as `#rrggbb` with lowercase digits.
+	///
+	/// ### Example
+	/// ```
+	/// use ex05::Color;
+	///
+	/// assert_eq!(Color::RED.to_hex(), "#ff0000");
+	/// ```
+	pub fn to_hex(self: &Self) -> String {
+		format!("#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+	}
+
+	/// Calculates the squared euclidean distance with another color.
+	///
+	/// ### Parameters
+	/// * `other` - The color to calculate the distance with.
+	///
+	/// ### Return
+	/// The squared euclidean distance between the RGB components of the two colors.
+	///
+	/// ### Example
+	/// ```
+	/// use ex05::Color;
+	///
+	/// assert_eq!(Color::RED.distance_squared(&Color::GREEN), 130050);
+	/// ```
+	pub fn distance_squared(self: &Self, other: &Self) -> u32 {
+		let diff_red: u32 = self.red.abs_diff(other.red) as u32;
+		let diff_green: u32 = self.green.abs_diff(other.green) as u32;
+		let diff_blue: u32 = self.blue.abs_diff(other.blue) as u32;
+
+		diff_red * diff_red + diff_green * diff_green + diff_blue * diff_blue
+	}
+
+	/// Calculates the euclidean distance with another color.
+	///
+	/// ### Parameters
+	/// * `other` - The color to calculate the distance with.
+	///
+	/// ### Return
+	/// The euclidean distance between the RGB components of the two colors.
+	///
+	/// ### Example
+	/// ```
+	/// use ex05::Color;
+	///
+	/// assert_eq!(Color::RED.distance(&Color::new(0xff, 0x03, 0x04)), 5.0);
+	/// ```
+	pub fn distance(self: &Self, other: &Self) -> f32 {
+		(self.distance_squared(other) as f32).sqrt()
+	}
+
+	/// Calculates the distance with another color, using a given metric.
+	///
+	/// ### Parameters
+	/// * `other` - The color to calculate the distance with.
+	/// * `metric` - The way of measuring the distance.
+	///
+	/// ### Return
+	/// The distance between the two colors.
+	///
+	/// ### Example
+	/// ```
+	/// use ex05::{Color, Metric};
+	///
+	/// assert_eq!(Color::RED.distance_with(&Color::WHITE, Metric::Euclidean), 130050);
+	/// assert_eq!(Color::RED.distance_with(&Color::WHITE, Metric::WeightedRgb), 4551750);
+	/// ```
+	pub fn distance_with(self: &Self, other: &Self, metric: Metric) -> u32 {
+		match metric {
+			Metric::Euclidean => self.distance_squared(other),
+			Metric::WeightedRgb => {
+				let diff_red: u32 = self.red.abs_diff(other.red) as u32;
+				let diff_green: u32 = self.green.abs_diff(other.green) as u32;
+				let diff_blue: u32 = self.blue.abs_diff(other.blue) as u32;
+
+				30 * diff_red * diff_red + 59 * diff_green * diff_green + 11 * diff_blue * diff_blue
+			}
+		}
+	}
+
+	/// Adds the color to a canvas with a given opacity, and returns the resulting color.
+	/// The canvas is assumed to be completly opaque.
+	///
+	/// ### Parameters
+	/// * `canvas` - The color of the canvas to add the color to.
+	/// * `opacity` - The opacity of the added color, from 0 (transparent) to 255 (opaque).
+	///
+	/// ### Return
+	/// The resulting color.
+	///
+	/// ### Example
+	/// ```
+	/// use ex05::Color;
+	///
+	/// assert_eq!(Color::RED.blend(&Color::WHITE, 128), Color::new(255, 127, 127));
+	/// assert_eq!(Color::RED.blend(&Color::WHITE, 0), Color::WHITE);
+	/// assert_eq!(Color::RED.blend(&Color::WHITE, 255), Color::RED);
+	/// ```
+	pub fn blend(self: &Self, canvas: &Self, opacity: u8) -> Self {
+		#[inline(always)]
+		fn mix_component(a: u8, b: u8, opacity: u8) -> u8 {
+			return ((a as u16 * opacity as u16 + b as u16 * (255 - opacity) as u16)
+				/ u8::MAX as u16) as u8;
+		}
+
+		Self::new(
+			mix_component(self.red, canvas.red, opacity),
+			mix_component(self.green, canvas.green, opacity),
+			mix_component(self.blue, canvas.blue, opacity),
+		)
+	}
+
 	/// Tries mixing colors as if painted on a white canvas to obtain a result as close as possible
 	/// to the calling instance.
 	///
@@ -140,23 +275,120 @@ impl Color {
 	/// 	Color::new(217, 34, 71)
 	/// );
 	/// ```
+	#[inline(always)]
 	pub fn closest_mix(self: &Self, palette: &[(Self, u8)], max: u32) -> Self {
+		self.closest_mix_trace(palette, max).0
+	}
+
+	/// Tries mixing colors as if painted on a white canvas to obtain a result as close as possible
+	/// to the calling instance, and keeps track of the palette colors that have been mixed.
+	///
+	/// ### Parameters
+	/// * `palette` - The palette of colors to mix.
+	/// * `max` - The maximum number of colors to mix.
+	///
+	/// ### Return
+	/// * The resulting mixed color that is closest to the `self` color.
+	/// * The indices in `palette` of the colors to mix to obtain it, in order,
+	///   starting from a white canvas.
+	///
+	/// ### Example
+	/// ```
+	/// use ex05::Color;
+	///
+	/// let palette: [(Color, u8); 2] = [(Color::RED, 255), (Color::BLUE, 128)];
+	///
+	/// assert_eq!(Color::RED.closest_mix_trace(&[], 100), (Color::WHITE, vec![]));
+	/// assert_eq!(Color::new(127, 0, 128).closest_mix_trace(&palette, 2), (Color::new(127, 0, 128), vec![0, 1]));
+	/// ```
+	pub fn closest_mix_trace(self: &Self, palette: &[(Self, u8)], max: u32) -> (Self, Vec<usize>) {
 		if *self == Self::WHITE || palette.len() == 0 || max == 0 {
-			return Self::WHITE;
+			return (Self::WHITE, Vec::new());
 		}
 
 		let mut closest: Self = Self::WHITE;
-		for number_of_colors_to_mix in 1..=max {
-			let current: Self =
-				self.mix_recursively(&Self::WHITE, &mut closest, palette, number_of_colors_to_mix);
-
-			if current == *self {
-				return current;
+		let mut seen: HashMap<Self, Option<(Self, usize)>> = HashMap::from([(Self::WHITE, None)]);
+		let mut canvases: Vec<Self> = vec![Self::WHITE];
+
+		// Each color is only mixed further the first time it is obtained,
+		// as mixing it again would only lead to colors that have already been obtained.
+		for _ in 0..max {
+			canvases = Self::mix_next_colors(&canvases, palette, &mut seen);
+			if canvases.is_empty() {
+				break;
+			}
+			for current in &canvases {
+				if current.distance_squared(self) < closest.distance_squared(self) {
+					if current == self {
+						return (*current, Self::mix_trace(*current, &seen));
+					}
+					closest = *current;
+				}
 			}
 		}
 
-		closest
+		(closest, Self::mix_trace(closest, &seen))
 	}
+
+	/// Finds out the color of a palette that is the closest to the calling instance.
+	///
+	/// ### Parameters
+	/// * `palette` - The palette of colors to search in.
+	///
+	/// ### Return
+	/// * `Some(usize)` - The index of the closest color in `palette`.
+	///   If several colors are equally close, the index of the first one is returned.
+	/// * `None` - `palette` is empty.
+	///
+	/// ### Example
+	/// ```
+	/// use ex05::Color;
+	///
+	/// assert_eq!(Color::RED.nearest_in(&[]), None);
+	/// assert_eq!(
+	/// 	Color::new(0xf0, 0x10, 0x08).nearest_in(&[Color::WHITE, Color::RED, Color::BLUE]),
+	/// 	Some(1)
+	/// );
+	/// ```
+	pub fn nearest_in(self: &Self, palette: &[Self]) -> Option<usize> {
+		palette
+			.iter()
+			.enumerate()
+			.min_by_key(|(_, color): &(usize, &Self)| color.distance_squared(self))
+			.map(|(i, _): (usize, &Self)| i)
+	}
+}
+
+/// Replaces every color of a set by the color of a palette that is the closest to it.
+///
+/// ### Parameters
+/// * `colors` - The colors to replace.
+/// * `palette` - The palette of colors to replace with.
+///
+/// ### Return
+/// The colors of `colors`, each replaced by its closest color in `palette`.
+/// If `palette` is empty, the colors are returned unchanged.
+///
+/// ### Example
+/// ```
+/// use ex05::{quantize, Color};
+///
+/// assert_eq!(
+/// 	quantize(
+/// 		&[Color::new(0xf0, 0x10, 0x08), Color::new(0x0a, 0x00, 0xe0)],
+/// 		&[Color::RED, Color::BLUE]
+/// 	),
+/// 	[Color::RED, Color::BLUE]
+/// );
+/// ```
+pub fn quantize(colors: &[Color], palette: &[Color]) -> Vec<Color> {
+	colors
+		.iter()
+		.map(|color: &Color| match color.nearest_in(palette) {
+			Some(i) => palette[i],
+			None => *color,
+		})
+		.collect()
 }
 
 #[cfg(test)]
@@ -400,4 +632,376 @@ mod tests {
 			Color::new(0x5e, 0xa7, 0x5c)
 		);
 	}
+
+	#[test]
+	#[timeout(250)]
+	fn closest_mix_13() {
+		assert_eq!(
+			Color::new(0x33, 0x02, 0xd1)
+				.closest_mix(&[(Color::RED, 0x42), (Color::GREEN, 0x42), (Color::BLUE, 0x42)], 11),
+			Color::new(0x33, 0x08, 0xd1)
+		);
+	}
+
+	#[test]
+	#[timeout(3000)]
+	fn closest_mix_14() {
+		assert_eq!(
+			Color::new(0x33, 0x02, 0xd1).closest_mix(
+				&[(Color::RED, 0x42), (Color::GREEN, 0x42), (Color::BLUE, 0x42)],
+				u32::MAX
+			),
+			Color::new(0x32, 0x03, 0xcf)
+		);
+	}
+
+	fn replay_trace(palette: &[(Color, u8)], trace: &[usize]) -> Color {
+		trace.iter().fold(Color::WHITE, |canvas: Color, i: &usize| {
+			let (color, opacity): (Color, u8) = palette[*i];
+
+			color.blend(&canvas, opacity)
+		})
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn closest_mix_trace_00() {
+		assert_eq!(Color::RED.closest_mix_trace(&[], 100), (Color::WHITE, Vec::new()));
+		assert_eq!(
+			Color::RED.closest_mix_trace(&[(Color::RED, 0xff)], 0),
+			(Color::WHITE, Vec::new())
+		);
+		assert_eq!(
+			Color::WHITE.closest_mix_trace(&[(Color::RED, 0xff)], 1),
+			(Color::WHITE, Vec::new())
+		);
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn closest_mix_trace_01() {
+		assert_eq!(
+			Color::BLUE.closest_mix_trace(&[(Color::RED, 0xff), (Color::BLUE, 0xff)], 3),
+			(Color::BLUE, vec![1])
+		);
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn closest_mix_trace_02() {
+		let palette: [(Color, u8); 2] = [(Color::RED, 0xff), (Color::BLUE, 0x80)];
+
+		assert_eq!(
+			Color::new(0x7f, 0x00, 0x80).closest_mix_trace(&palette, 2),
+			(Color::new(0x7f, 0x00, 0x80), vec![0, 1])
+		);
+	}
+
+	#[test]
+	#[timeout(1000)]
+	fn closest_mix_trace_03() {
+		let palette: [(Color, u8); 3] =
+			[(Color::RED, 0x42), (Color::GREEN, 0x42), (Color::BLUE, 0x42)];
+		let (color, trace): (Color, Vec<usize>) =
+			Color::new(0x58, 0xe4, 0x0a).closest_mix_trace(&palette, 6);
+
+		assert!(trace.len() <= 6);
+		assert_eq!(replay_trace(&palette, &trace), color);
+		assert_eq!(color, Color::new(0x58, 0xe4, 0x0a).closest_mix(&palette, 6));
+	}
+
+	#[test]
+	#[timeout(1000)]
+	fn closest_mix_trace_04() {
+		let palette: [(Color, u8); 5] = [
+			(Color::new(0x00, 0x00, 0x00), 0x21),
+			(Color::new(0x1c, 0xdb, 0x81), 0xa2),
+			(Color::new(0x8e, 0x49, 0xa3), 0x14),
+			(Color::new(0x3f, 0x0e, 0xb6), 0xe4),
+			(Color::new(0xd8, 0x44, 0x15), 0x9b),
+		];
+		let (color, trace): (Color, Vec<usize>) =
+			Color::new(0x58, 0xe4, 0x0a).closest_mix_trace(&palette, 5);
+
+		assert_eq!(color, Color::new(0x5e, 0xa7, 0x5c));
+		assert!(trace.len() <= 5);
+		assert_eq!(replay_trace(&palette, &trace), color);
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn nearest_in_00() {
+		assert_eq!(Color::RED.nearest_in(&[]), None);
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn nearest_in_01() {
+		assert_eq!(Color::RED.nearest_in(&[Color::BLUE]), Some(0));
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn nearest_in_02() {
+		assert_eq!(
+			Color::new(0xf0, 0x10, 0x08).nearest_in(&[
+				Color::WHITE,
+				Color::GREEN,
+				Color::RED,
+				Color::BLUE,
+			]),
+			Some(2)
+		);
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn nearest_in_03() {
+		assert_eq!(
+			Color::new(0x80, 0x80, 0x80).nearest_in(&[
+				Color::new(0x00, 0x00, 0x00),
+				Color::new(0x7f, 0x7f, 0x7f),
+				Color::new(0x81, 0x81, 0x81),
+				Color::WHITE,
+			]),
+			Some(1)
+		);
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn nearest_in_04() {
+		assert_eq!(Color::GREEN.nearest_in(&[Color::RED, Color::GREEN, Color::GREEN]), Some(1));
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn quantize_00() {
+		assert_eq!(quantize(&[], &[Color::RED]), []);
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn quantize_01() {
+		assert_eq!(
+			quantize(&[Color::new(0x12, 0x34, 0x56), Color::GREEN], &[]),
+			[Color::new(0x12, 0x34, 0x56), Color::GREEN]
+		);
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn quantize_02() {
+		assert_eq!(
+			quantize(
+				&[Color::new(0xf0, 0x10, 0x08), Color::new(0x0a, 0x00, 0xe0)],
+				&[Color::RED, Color::BLUE]
+			),
+			[Color::RED, Color::BLUE]
+		);
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn quantize_03() {
+		assert_eq!(
+			quantize(
+				&[
+					Color::new(0x10, 0x10, 0x10),
+					Color::new(0xe0, 0xe0, 0xe0),
+					Color::new(0x70, 0x70, 0x70),
+					Color::new(0x90, 0x90, 0x90),
+				],
+				&[Color::new(0x00, 0x00, 0x00), Color::WHITE]
+			),
+			[
+				Color::new(0x00, 0x00, 0x00),
+				Color::WHITE,
+				Color::new(0x00, 0x00, 0x00),
+				Color::WHITE,
+			]
+		);
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn distance_with_00() {
+		for metric in [Metric::Euclidean, Metric::WeightedRgb] {
+			assert_eq!(Color::RED.distance_with(&Color::RED, metric), 0);
+		}
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn distance_with_01() {
+		let black: Color = Color::new(0x00, 0x00, 0x00);
+
+		assert_eq!(Color::WHITE.distance_with(&black, Metric::Euclidean), 195075);
+		assert_eq!(Color::WHITE.distance_with(&black, Metric::WeightedRgb), 6502500);
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn distance_with_02() {
+		let color: Color = Color::new(0x12, 0x34, 0x56);
+		let other: Color = Color::new(0xfe, 0xdc, 0xba);
+
+		for metric in [Metric::Euclidean, Metric::WeightedRgb] {
+			assert_eq!(color.distance_with(&other, metric), other.distance_with(&color, metric));
+		}
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn distance_with_03() {
+		let grey: Color = Color::new(0x80, 0x80, 0x80);
+		let redder: Color = Color::new(0xa8, 0x80, 0x80);
+		let greener: Color = Color::new(0x80, 0x9e, 0x80);
+
+		assert!(
+			grey.distance_with(&greener, Metric::Euclidean)
+				< grey.distance_with(&redder, Metric::Euclidean)
+		);
+		assert!(
+			grey.distance_with(&redder, Metric::WeightedRgb)
+				< grey.distance_with(&greener, Metric::WeightedRgb)
+		);
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn distance_squared_00() {
+		assert_eq!(Color::RED.distance_squared(&Color::RED), 0);
+		assert_eq!(Color::RED.distance(&Color::RED), 0.0);
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn distance_squared_01() {
+		assert_eq!(Color::RED.distance_squared(&Color::GREEN), 130050);
+		assert_eq!(Color::GREEN.distance_squared(&Color::RED), 130050);
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn distance_squared_02() {
+		let distance: f32 = Color::RED.distance(&Color::GREEN);
+
+		assert!((distance - 255.0 * std::f32::consts::SQRT_2).abs() < 0.001);
+		assert!(
+			(distance * distance - Color::RED.distance_squared(&Color::GREEN) as f32).abs() < 0.1
+		);
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn distance_squared_03() {
+		let black: Color = Color::new(0x00, 0x00, 0x00);
+
+		assert_eq!(Color::WHITE.distance_squared(&black), 195075);
+		assert_eq!(Color::WHITE.distance_squared(&black), black.distance_squared(&Color::WHITE));
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn blend_00() {
+		assert_eq!(Color::RED.blend(&Color::WHITE, 0), Color::WHITE);
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn blend_01() {
+		assert_eq!(Color::RED.blend(&Color::WHITE, 255), Color::RED);
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn blend_02() {
+		assert_eq!(Color::RED.blend(&Color::WHITE, 128), Color::new(0xff, 0x7f, 0x7f));
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn blend_03() {
+		let color: Color = Color::new(0x12, 0x34, 0x56);
+		let canvas: Color = Color::new(0xfe, 0xdc, 0xba);
+
+		assert_eq!(color.blend(&canvas, 0x00), canvas);
+		assert_eq!(color.blend(&canvas, 0xff), color);
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn blend_04() {
+		assert_eq!(
+			Color::new(0x00, 0x00, 0x00).blend(&Color::new(0xff, 0x80, 0x01), 0x80),
+			Color::new(0x7f, 0x3f, 0x00)
+		);
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn from_hex_00() {
+		assert_eq!(Color::from_hex("#ff0000"), Some(Color::RED));
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn from_hex_01() {
+		assert_eq!(Color::from_hex("00FF00"), Some(Color::GREEN));
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn from_hex_02() {
+		assert_eq!(Color::from_hex("#12aB3c"), Some(Color::new(0x12, 0xab, 0x3c)));
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn from_hex_03() {
+		assert_eq!(Color::from_hex("#ff00"), None);
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn from_hex_04() {
+		assert_eq!(Color::from_hex("#gggggg"), None);
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn from_hex_05() {
+		for s in ["", "#", "##ff0000", "#ff00000", " ff0000", "+f+f+f", "#ff00é"] {
+			assert_eq!(Color::from_hex(s), None);
+		}
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn to_hex_00() {
+		assert_eq!(Color::RED.to_hex(), "#ff0000");
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn to_hex_01() {
+		assert_eq!(Color::new(0x01, 0xab, 0x0c).to_hex(), "#01ab0c");
+	}
+
+	#[test]
+	#[timeout(25)]
+	fn to_hex_02() {
+		for color in [
+			Color::new(0x00, 0x00, 0x00),
+			Color::WHITE,
+			Color::RED,
+			Color::GREEN,
+			Color::BLUE,
+			Color::new(0x12, 0x34, 0x56),
+			Color::new(0xfe, 0xdc, 0xba),
+		] {
+			assert_eq!(Color::from_hex(&color.to_hex()), Some(color));
+		}
+	}
 }
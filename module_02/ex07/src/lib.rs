@@ -0,0 +1,1009 @@
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Cell {
+	Dead,
+	Alive,
+}
+
+impl Cell {
+	/// Checks if the cell is alive.
+	///
+	/// ### Return
+	/// * `true` - The cell is alive.
+	/// * `false` - The cell is not alive.
+	#[inline(always)]
+	pub fn is_alive(self: &Self) -> bool {
+		*self == Cell::Alive
+	}
+
+	/// Checks if the cell is dead.
+	///
+	/// ### Return
+	/// * `true` - The cell is dead.
+	/// * `false` - The cell is not dead.
+	#[inline(always)]
+	pub fn is_dead(self: &Self) -> bool {
+		*self == Cell::Dead
+	}
+}
+
+/// A xorshift pseudo-random number generator,
+/// used to generate reproducible boards.
+struct XorShift {
+	state: u64,
+}
+
+impl XorShift {
+	/// Creates a new XorShift instance and initializes its attributes.
+	///
+	/// ### Parameters
+	/// * `seed` - The seed of the generator.
+	///
+	/// ### Return
+	/// The newly created XorShift instance.
+	#[inline(always)]
+	fn new(seed: u64) -> Self {
+		// A null state would only ever generate null numbers.
+		const FALLBACK_SEED: u64 = 0x9e37_79b9_7f4a_7c15;
+
+		return Self { state: if seed == 0 { FALLBACK_SEED } else { seed } };
+	}
+
+	/// Generates the next pseudo-random number.
+	///
+	/// ### Return
+	/// The generated number.
+	fn next(self: &mut Self) -> u64 {
+		self.state ^= self.state << 13;
+		self.state ^= self.state >> 7;
+		self.state ^= self.state << 17;
+
+		return self.state;
+	}
+}
+
+/// The way the edges of a board are handled when gathering the neighbors of a cell.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Topology {
+	/// The left and right edges are connected, and so are the top and bottom edges.
+	Torus,
+	/// The cells beyond the edges are considered dead.
+	Bounded,
+}
+
+/// The outcome of a single step of the game.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StepOutcome {
+	/// The board is the same as before the step.
+	Unchanged,
+	/// At least one cell changed, and some cells are still alive.
+	Changed,
+	/// Every cell of the board is dead.
+	Extinct,
+}
+
+pub struct Board {
+	width: usize,
+	height: usize,
+	cells: Vec<Cell>,
+	topology: Topology,
+}
+
+impl Board {
+	/// Creates a new Board instance and initializes its attributes.
+	/// The generated board will contains a certain percentage of alive cells,
+	/// and their posistions will be random.
+	///
+	/// ### Parameters
+	/// * `width` - The width of the board.
+	/// * `height` - The height of the board.
+	/// * `percentage` - The percentage of alive cells.
+	///
+	/// ### Return
+	/// The newly created Board instance, using a torus topology.
+	///
+	/// ### Panic
+	/// `percentage` is greater than 100.
+	#[inline(always)]
+	pub fn new(width: usize, height: usize, percentage: u8) -> Self {
+		use ftkit::random_number;
+
+		let seed: u64 = (random_number(i32::MIN..i32::MAX) as u32 as u64) << 32
+			| random_number(i32::MIN..i32::MAX) as u32 as u64;
+
+		return Self::with_seed(width, height, percentage, seed);
+	}
+
+	/// Creates a new Board instance and initializes its attributes.
+	/// The generated board will contains a certain percentage of alive cells,
+	/// and their posistions will be pseudo-random,
+	/// so that the same seed always generates the same board.
+	///
+	/// ### Parameters
+	/// * `width` - The width of the board.
+	/// * `height` - The height of the board.
+	/// * `percentage` - The percentage of alive cells.
+	/// * `seed` - The seed of the pseudo-random number generator.
+	///
+	/// ### Return
+	/// The newly created Board instance, using a torus topology.
+	///
+	/// ### Panic
+	/// `percentage` is greater than 100.
+	pub fn with_seed(width: usize, height: usize, percentage: u8, seed: u64) -> Self {
+		if percentage > 100 {
+			panic!("Percentage is greater than 100");
+		}
+
+		let mut rng: XorShift = XorShift::new(seed);
+
+		return Self {
+			width,
+			height,
+			cells: {
+				let vec_len: usize = width * height;
+				let alive_cell_count: usize = percentage as usize * vec_len / 100;
+				let mut cells: Vec<Cell> = vec![Cell::Dead; vec_len];
+
+				for _ in 0..alive_cell_count {
+					loop {
+						let i: usize = (rng.next() % vec_len as u64) as usize;
+
+						if cells[i] == Cell::Dead {
+							cells[i] = Cell::Alive;
+							break;
+						}
+					}
+				}
+				cells
+			},
+			topology: Topology::Torus,
+		};
+	}
+
+	/// Creates a new Board instance from a known state.
+	///
+	/// ### Parameters
+	/// * `width` - The width of the board.
+	/// * `height` - The height of the board.
+	/// * `cells` - The cells of the board, row by row.
+	///
+	/// ### Return
+	/// The newly created Board instance, using a torus topology.
+	///
+	/// ### Panic
+	/// The number of cells does not match the dimensions of the board.
+	///
+	/// ### Example
+	/// ```
+	/// use ex07::{Board, Cell};
+	///
+	/// let board: Board = Board::from_cells(2, 1, vec![Cell::Alive, Cell::Dead]);
+	/// ```
+	pub fn from_cells(width: usize, height: usize, cells: Vec<Cell>) -> Self {
+		if cells.len() != width * height {
+			panic!("Cell count does not match the board dimensions");
+		}
+
+		return Self { width, height, cells, topology: Topology::Torus };
+	}
+
+	/// Changes the way the edges of the board are handled.
+	///
+	/// ### Parameters
+	/// * `topology` - The new topology of the board.
+	///
+	/// ### Return
+	/// The updated Board instance.
+	///
+	/// ### Example
+	/// ```
+	/// use ex07::{Board, Cell, Topology};
+	///
+	/// let board: Board = Board::from_cells(1, 1, vec![Cell::Alive]).with_topology(Topology::Bounded);
+	/// ```
+	#[inline(always)]
+	pub fn with_topology(self: Self, topology: Topology) -> Self {
+		return Self { topology, ..self };
+	}
+
+	/// ### Return
+	/// The cells of the board, row by row.
+	#[inline(always)]
+	pub fn cells(self: &Self) -> &[Cell] {
+		return &self.cells;
+	}
+
+	/// ### Return
+	/// The number of alive cells of the board.
+	#[inline(always)]
+	pub fn alive_count(self: &Self) -> usize {
+		return self.cells.iter().filter(|cell: &&Cell| cell.is_alive()).count();
+	}
+
+	/// Simulates the next step of the game, and reports how the board evolved.
+	///
+	/// ### Return
+	/// * `StepOutcome::Extinct` - Every cell is dead after the step.
+	/// * `StepOutcome::Unchanged` - The step did not change any cell.
+	/// * `StepOutcome::Changed` - The step changed at least one cell.
+	///
+	/// ### Example
+	/// ```
+	/// use ex07::{Board, Cell, StepOutcome, Topology};
+	///
+	/// let mut board: Board = Board::from_cells(2, 2, vec![Cell::Alive; 4]).with_topology(Topology::Bounded);
+	///
+	/// assert_eq!(board.step_detect(), StepOutcome::Unchanged);
+	/// ```
+	pub fn step_detect(self: &mut Self) -> StepOutcome {
+		let previous_cells: Vec<Cell> = self.cells.clone();
+
+		self.step();
+		if self.alive_count() == 0 {
+			return StepOutcome::Extinct;
+		}
+		if self.cells == previous_cells {
+			return StepOutcome::Unchanged;
+		}
+		return StepOutcome::Changed;
+	}
+
+	/// Simulates the next step of the game.
+	/// If the board is a torus:
+	/// - the left and right edges are connected
+	/// - the top and bottom edges are connected
+	///
+	/// If the board is bounded, the cells beyond the edges are considered dead.
+	pub fn step(&mut self) {
+		#[inline(always)]
+		fn alive_neighbor_count(neighbors: &[Cell]) -> u8 {
+			let mut count: u8 = 0;
+
+			for i in 0..neighbors.len() {
+				if neighbors[i].is_alive() {
+					count += 1;
+				}
+			}
+
+			return count;
+		}
+
+		// region: Empty board
+		if self.width == 0 || self.height == 0 {
+			return;
+		}
+		// endregion
+
+		let mut new_cells: Vec<Cell> = vec![Cell::Dead; self.cells.len()];
+
+		// region: Bounded topology
+		if self.topology == Topology::Bounded {
+			for y in 0..self.height {
+				for x in 0..self.width {
+					let mut count: u8 = 0;
+
+					for neighbor_y in y.saturating_sub(1)..=(y + 1).min(self.height - 1) {
+						for neighbor_x in x.saturating_sub(1)..=(x + 1).min(self.width - 1) {
+							if (neighbor_x, neighbor_y) != (x, y)
+								&& self.cells[self.width * neighbor_y + neighbor_x].is_alive()
+							{
+								count += 1;
+							}
+						}
+					}
+					if count == 3 || (count == 2 && self.cells[self.width * y + x].is_alive()) {
+						new_cells[self.width * y + x] = Cell::Alive;
+					}
+				}
+			}
+
+			self.cells = new_cells;
+			return;
+		}
+		// endregion
+
+		// region: Torus topology
+		// region: Easy edge cases
+		if self.width == 1 && self.height == 1 {
+			self.cells[0] = Cell::Dead;
+			return;
+		}
+		// endregion
+
+		let mut neighbors: [Cell; 8];
+
+		// region: More complex edge cases
+		if self.width == 1 || self.height == 1 {
+			// region: Extremity cells
+			let last: usize = self.cells.len() - 1;
+			let penultimate: usize = last - 1;
+
+			// region: First cell
+			neighbors = [
+				self.cells[last],
+				self.cells[last],
+				self.cells[last],
+				self.cells[0],
+				self.cells[0],
+				self.cells[1],
+				self.cells[1],
+				self.cells[1],
+			];
+			if self.cells[0].is_alive() {
+				match alive_neighbor_count(&neighbors) {
+					2 | 3 => new_cells[0] = Cell::Alive,
+					_ => (),
+				}
+			} else if alive_neighbor_count(&neighbors) == 3 {
+				new_cells[0] = Cell::Alive;
+			}
+			// endregion
+
+			// region: Last cell
+			neighbors = [
+				self.cells[penultimate],
+				self.cells[penultimate],
+				self.cells[penultimate],
+				self.cells[last],
+				self.cells[last],
+				self.cells[0],
+				self.cells[0],
+				self.cells[0],
+			];
+			if self.cells[last].is_alive() {
+				match alive_neighbor_count(&neighbors) {
+					2 | 3 => new_cells[last] = Cell::Alive,
+					_ => (),
+				}
+			} else if alive_neighbor_count(&neighbors) == 3 {
+				new_cells[last] = Cell::Alive;
+			}
+			// endregion
+			// endregion
+
+			// region: Intermediate cells
+			for i in 1..self.cells.len() - 1 {
+				neighbors = [
+					self.cells[i - 1],
+					self.cells[i - 1],
+					self.cells[i - 1],
+					self.cells[i],
+					self.cells[i],
+					self.cells[i + 1],
+					self.cells[i + 1],
+					self.cells[i + 1],
+				];
+
+				if self.cells[i].is_alive() {
+					match alive_neighbor_count(&neighbors) {
+						2 | 3 => new_cells[i] = Cell::Alive,
+						_ => (),
+					}
+				} else if alive_neighbor_count(&neighbors) == 3 {
+					new_cells[i] = Cell::Alive;
+				}
+			}
+			// endregion
+
+			self.cells = new_cells;
+			return;
+		}
+		// endregion
+
+		// region: Common cases
+		// region: Corners
+		const TOP_LEFT: usize = 0;
+		const TOP_RIGHT: usize = 1;
+		const BOTTOM_LEFT: usize = 2;
+		const BOTTOM_RIGHT: usize = 3;
+
+		let areas: [[Cell; 4]; 4] = [
+			[
+				self.cells[0],
+				self.cells[1],
+				self.cells[self.width],
+				self.cells[self.width + 1],
+			],
+			[
+				self.cells[self.width - 2],
+				self.cells[self.width - 1],
+				self.cells[self.width * 2 - 2],
+				self.cells[self.width * 2 - 1],
+			],
+			[
+				self.cells[self.cells.len() - (self.width * 2)],
+				self.cells[self.cells.len() - (self.width * 2) + 1],
+				self.cells[self.cells.len() - self.width],
+				self.cells[self.cells.len() - self.width + 1],
+			],
+			[
+				self.cells[self.cells.len() - self.width - 2],
+				self.cells[self.cells.len() - self.width - 1],
+				self.cells[self.cells.len() - 2],
+				self.cells[self.cells.len() - 1],
+			],
+		];
+
+		// region: Top-left corner
+		neighbors = [
+			areas[BOTTOM_RIGHT][BOTTOM_RIGHT],
+			areas[BOTTOM_LEFT][BOTTOM_LEFT],
+			areas[BOTTOM_LEFT][BOTTOM_RIGHT],
+			areas[TOP_RIGHT][TOP_RIGHT],
+			areas[TOP_LEFT][TOP_RIGHT],
+			areas[TOP_RIGHT][BOTTOM_RIGHT],
+			areas[TOP_LEFT][BOTTOM_LEFT],
+			areas[TOP_LEFT][BOTTOM_RIGHT],
+		];
+		if areas[TOP_LEFT][TOP_LEFT].is_alive() {
+			match alive_neighbor_count(&neighbors) {
+				2 | 3 => new_cells[0] = Cell::Alive,
+				_ => (),
+			}
+		} else if alive_neighbor_count(&neighbors) == 3 {
+			new_cells[0] = Cell::Alive;
+		}
+		// endregion
+
+		// region: Top-right corner
+		neighbors = [
+			areas[BOTTOM_RIGHT][BOTTOM_LEFT],
+			areas[BOTTOM_RIGHT][BOTTOM_RIGHT],
+			areas[BOTTOM_LEFT][BOTTOM_LEFT],
+			areas[TOP_RIGHT][TOP_LEFT],
+			areas[TOP_LEFT][TOP_LEFT],
+			areas[TOP_RIGHT][BOTTOM_LEFT],
+			areas[TOP_RIGHT][BOTTOM_RIGHT],
+			areas[TOP_LEFT][BOTTOM_LEFT],
+		];
+		if areas[TOP_RIGHT][TOP_RIGHT].is_alive() {
+			match alive_neighbor_count(&neighbors) {
+				2 | 3 => new_cells[self.width - 1] = Cell::Alive,
+				_ => (),
+			}
+		} else if alive_neighbor_count(&neighbors) == 3 {
+			new_cells[self.width - 1] = Cell::Alive;
+		}
+		// endregion
+
+		// region: Bottom-left corner
+		neighbors = [
+			areas[BOTTOM_RIGHT][TOP_RIGHT],
+			areas[BOTTOM_LEFT][TOP_LEFT],
+			areas[BOTTOM_LEFT][TOP_RIGHT],
+			areas[BOTTOM_RIGHT][BOTTOM_RIGHT],
+			areas[BOTTOM_LEFT][BOTTOM_RIGHT],
+			areas[TOP_RIGHT][TOP_RIGHT],
+			areas[TOP_LEFT][TOP_LEFT],
+			areas[TOP_LEFT][TOP_RIGHT],
+		];
+		if areas[BOTTOM_LEFT][BOTTOM_LEFT].is_alive() {
+			match alive_neighbor_count(&neighbors) {
+				2 | 3 => new_cells[self.width * (self.height - 1)] = Cell::Alive,
+				_ => (),
+			}
+		} else if alive_neighbor_count(&neighbors) == 3 {
+			new_cells[self.width * (self.height - 1)] = Cell::Alive;
+		}
+		// endregion
+
+		// region: Bottom-right corner
+		neighbors = [
+			areas[BOTTOM_RIGHT][TOP_LEFT],
+			areas[BOTTOM_RIGHT][TOP_RIGHT],
+			areas[BOTTOM_LEFT][TOP_LEFT],
+			areas[BOTTOM_RIGHT][BOTTOM_LEFT],
+			areas[BOTTOM_LEFT][BOTTOM_LEFT],
+			areas[TOP_RIGHT][TOP_LEFT],
+			areas[TOP_RIGHT][TOP_RIGHT],
+			areas[TOP_LEFT][TOP_LEFT],
+		];
+		if areas[BOTTOM_RIGHT][BOTTOM_RIGHT].is_alive() {
+			match alive_neighbor_count(&neighbors) {
+				2 | 3 => new_cells[self.width * self.height - 1] = Cell::Alive,
+				_ => (),
+			}
+		} else if alive_neighbor_count(&neighbors) == 3 {
+			new_cells[self.width * self.height - 1] = Cell::Alive;
+		}
+		// endregion
+		// endregion
+
+		// region: Edges
+		// region: Left & Right edges
+		for y in 1..self.height - 1 {
+			// region: Left edge
+			neighbors = [
+				self.cells[self.width * y - 1],
+				self.cells[self.width * (y - 1)],
+				self.cells[self.width * (y - 1) + 1],
+				self.cells[self.width * (y + 1) - 1],
+				self.cells[self.width * y + 1],
+				self.cells[self.width * (y + 2) - 1],
+				self.cells[self.width * (y + 1)],
+				self.cells[self.width * (y + 1) + 1],
+			];
+			if self.cells[self.width * y].is_alive() {
+				match alive_neighbor_count(&neighbors) {
+					2 | 3 => new_cells[self.width * y] = Cell::Alive,
+					_ => (),
+				}
+			} else if alive_neighbor_count(&neighbors) == 3 {
+				new_cells[self.width * y] = Cell::Alive;
+			}
+			// endregion
+
+			// region: Right edge
+			neighbors = [
+				self.cells[self.width * y - 2],
+				self.cells[self.width * y - 1],
+				self.cells[self.width * (y - 1)],
+				self.cells[self.width * (y + 1) - 2],
+				self.cells[self.width * y],
+				self.cells[self.width * (y + 2) - 2],
+				self.cells[self.width * (y + 2) - 1],
+				self.cells[self.width * (y + 1)],
+			];
+			if self.cells[self.width * (y + 1) - 1].is_alive() {
+				match alive_neighbor_count(&neighbors) {
+					2 | 3 => new_cells[self.width * (y + 1) - 1] = Cell::Alive,
+					_ => (),
+				}
+			} else if alive_neighbor_count(&neighbors) == 3 {
+				new_cells[self.width * (y + 1) - 1] = Cell::Alive;
+			}
+			// endregion
+		}
+		// endregion
+
+		// region: Top & Bottom edges
+		for x in 1..self.width - 1 {
+			// region: Top edge
+			neighbors = [
+				self.cells[x + self.cells.len() - self.width - 1],
+				self.cells[x + self.cells.len() - self.width],
+				self.cells[x + self.cells.len() - self.width + 1],
+				self.cells[x - 1],
+				self.cells[x + 1],
+				self.cells[x + self.width - 1],
+				self.cells[x + self.width],
+				self.cells[x + self.width + 1],
+			];
+			if self.cells[x].is_alive() {
+				match alive_neighbor_count(&neighbors) {
+					2 | 3 => new_cells[x] = Cell::Alive,
+					_ => (),
+				}
+			} else if alive_neighbor_count(&neighbors) == 3 {
+				new_cells[x] = Cell::Alive;
+			}
+			// endregion
+
+			// region: Bottom edge
+			neighbors = [
+				self.cells[x + self.cells.len() - self.width * 2 - 1],
+				self.cells[x + self.cells.len() - self.width * 2],
+				self.cells[x + self.cells.len() - self.width * 2 + 1],
+				self.cells[x + self.cells.len() - self.width - 1],
+				self.cells[x + self.cells.len() - self.width + 1],
+				self.cells[x - 1],
+				self.cells[x],
+				self.cells[x + 1],
+			];
+			if self.cells[x + self.cells.len() - self.width].is_alive() {
+				match alive_neighbor_count(&neighbors) {
+					2 | 3 => new_cells[x + self.cells.len() - self.width] = Cell::Alive,
+					_ => (),
+				}
+			} else if alive_neighbor_count(&neighbors) == 3 {
+				new_cells[x + self.cells.len() - self.width] = Cell::Alive;
+			}
+			// endregion
+		}
+		// endregion
+		// endregion
+
+		// region: Center area
+		for y in 1..self.height - 1 {
+			for x in 1..self.width - 1 {
+				neighbors = [
+					self.cells[self.width * (y - 1) + x - 1],
+					self.cells[self.width * (y - 1) + x],
+					self.cells[self.width * (y - 1) + x + 1],
+					self.cells[self.width * y + x - 1],
+					self.cells[self.width * y + x + 1],
+					self.cells[self.width * (y + 1) + x - 1],
+					self.cells[self.width * (y + 1) + x],
+					self.cells[self.width * (y + 1) + x + 1],
+				];
+				if self.cells[self.width * y + x].is_alive() {
+					match alive_neighbor_count(&neighbors) {
+						2 | 3 => new_cells[self.width * y + x] = Cell::Alive,
+						_ => (),
+					}
+				} else if alive_neighbor_count(&neighbors) == 3 {
+					new_cells[self.width * y + x] = Cell::Alive;
+				}
+			}
+		}
+		// endregion
+		// endregion
+		// endregion
+
+		self.cells = new_cells;
+	}
+
+	/// Displays the board on stdout.
+	///
+	/// ### Parameters
+	/// * `clear` - If `true`, clear a previously displayed board before displaying the new one.
+	///
+	/// ### Example
+	/// ```
+	/// use ex07::Board;
+	///
+	/// let board: Board = Board::new(42, 42, 42);
+	/// board.print(false);
+	/// board.print(true);
+	/// ```
+	pub fn print(self: &Self, clear: bool) {
+		const BORDER_COLOR: &str = "\x1b[48;2;175;175;175m";
+		const ALIVE_COLOR: &str = "\x1b[48;2;255;153;0m";
+		const DEAD_COLOR: &str = "\x1b[48;2;0;0;0m";
+		const RESET: &str = "\x1b[0m";
+
+		fn print_horizontal_border(width: usize) {
+			print!("{BORDER_COLOR}");
+			for _ in 0..width + 2 {
+				print!("  ");
+			}
+			println!("{RESET}");
+		}
+
+		if clear {
+			print!("\x1b[{}A", self.height + 2);
+		}
+
+		print_horizontal_border(self.width);
+		for y in 0..self.height {
+			print!("{BORDER_COLOR}  ");
+			for x in 0..self.width {
+				if self.cells[self.width * y + x].is_alive() {
+					print!("{ALIVE_COLOR}  ");
+				} else {
+					print!("{DEAD_COLOR}  ");
+				}
+			}
+			println!("{BORDER_COLOR}  {RESET}");
+		}
+		print_horizontal_border(self.width);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Creates a new Board instance from a textual representation of its cells,
+	/// where `'#'` is an alive cell and `'.'` is a dead one.
+	fn board_from_rows(rows: &[&str]) -> Board {
+		let cells: Vec<Cell> = rows
+			.iter()
+			.flat_map(|row: &&str| row.chars())
+			.map(|c: char| if c == '#' { Cell::Alive } else { Cell::Dead })
+			.collect();
+
+		Board::from_cells(rows.first().map_or(0, |row: &&str| row.len()), rows.len(), cells)
+	}
+
+	// region: with_seed_00
+	#[test]
+	fn with_seed_00() {
+		let board0: Board = Board::with_seed(42, 21, 42, 0x2a);
+		let board1: Board = Board::with_seed(42, 21, 42, 0x2a);
+
+		assert_eq!(board0.width, 42);
+		assert_eq!(board0.height, 21);
+		assert_eq!(board0.cells.len(), 42 * 21);
+		assert_eq!(board0.cells == board1.cells, true);
+	}
+	// endregion
+
+	// region: with_seed_01
+	#[test]
+	fn with_seed_01() {
+		let board0: Board = Board::with_seed(42, 21, 42, 0x2a);
+		let board1: Board = Board::with_seed(42, 21, 42, 0x2b);
+
+		assert_eq!(board0.cells == board1.cells, false);
+	}
+	// endregion
+
+	// region: with_seed_02
+	#[test]
+	fn with_seed_02() {
+		for percentage in [0, 1, 42, 99, 100] {
+			let board: Board = Board::with_seed(17, 13, percentage, 0);
+			let alive_cell_count: usize =
+				board.cells.iter().filter(|cell: &&Cell| cell.is_alive()).count();
+
+			assert_eq!(alive_cell_count, percentage as usize * 17 * 13 / 100);
+		}
+	}
+	// endregion
+
+	// region: with_seed_03
+	#[test]
+	fn with_seed_03() {
+		let board0: Board = Board::with_seed(0, 0, 100, 0);
+		let board1: Board = Board::with_seed(0, 42, 100, 0);
+
+		assert_eq!(board0.cells.len(), 0);
+		assert_eq!(board1.cells.len(), 0);
+	}
+	// endregion
+
+	// region: with_seed_04
+	#[test]
+	#[should_panic(expected = "Percentage is greater than 100")]
+	fn with_seed_04() {
+		Board::with_seed(10, 10, 101, 0);
+	}
+	// endregion
+
+	// region: with_seed_05
+	#[test]
+	#[should_panic(expected = "Percentage is greater than 100")]
+	fn with_seed_05() {
+		Board::with_seed(0, 0, u8::MAX, 0);
+	}
+	// endregion
+
+	// region: from_cells_00
+	#[test]
+	fn from_cells_00() {
+		let board: Board = Board::from_cells(3, 2, vec![Cell::Alive; 6]);
+
+		assert_eq!(board.width, 3);
+		assert_eq!(board.height, 2);
+		assert_eq!(board.cells(), [Cell::Alive; 6]);
+	}
+	// endregion
+
+	// region: from_cells_01
+	#[test]
+	#[should_panic(expected = "Cell count does not match the board dimensions")]
+	fn from_cells_01() {
+		Board::from_cells(3, 2, vec![Cell::Alive; 5]);
+	}
+	// endregion
+
+	// region: step_00
+	#[test]
+	fn step_00() {
+		let horizontal: Board = board_from_rows(&[".....", ".....", ".###.", ".....", "....."]);
+		let vertical: Board = board_from_rows(&[".....", "..#..", "..#..", "..#..", "....."]);
+		let mut board: Board = board_from_rows(&[".....", ".....", ".###.", ".....", "....."]);
+
+		for _ in 0..4 {
+			board.step();
+			assert_eq!(board.cells(), vertical.cells());
+			board.step();
+			assert_eq!(board.cells(), horizontal.cells());
+		}
+	}
+	// endregion
+
+	// region: step_01
+	#[test]
+	fn step_01() {
+		let block: Board = board_from_rows(&["....", ".##.", ".##.", "...."]);
+		let mut board: Board = board_from_rows(&["....", ".##.", ".##.", "...."]);
+
+		for _ in 0..4 {
+			board.step();
+			assert_eq!(board.cells(), block.cells());
+		}
+	}
+	// endregion
+
+	// region: step_02
+	#[test]
+	fn step_02() {
+		let block: Board = board_from_rows(&["#..#", "....", "....", "#..#"]);
+		let mut board: Board = board_from_rows(&["#..#", "....", "....", "#..#"]);
+
+		for _ in 0..4 {
+			board.step();
+			assert_eq!(board.cells(), block.cells());
+		}
+	}
+	// endregion
+
+	// region: step_03
+	#[test]
+	fn step_03() {
+		let horizontal: Board = board_from_rows(&["##..#", ".....", ".....", ".....", "....."]);
+		let vertical: Board = board_from_rows(&["#....", "#....", ".....", ".....", "#...."]);
+		let mut board: Board = board_from_rows(&["#....", "#....", ".....", ".....", "#...."]);
+
+		for _ in 0..4 {
+			board.step();
+			assert_eq!(board.cells(), horizontal.cells());
+			board.step();
+			assert_eq!(board.cells(), vertical.cells());
+		}
+	}
+	// endregion
+
+	// region: step_04
+	#[test]
+	fn step_04() {
+		let mut board: Board = Board::from_cells(1, 1, vec![Cell::Alive]);
+
+		board.step();
+		assert_eq!(board.cells(), [Cell::Dead]);
+	}
+	// endregion
+
+	// region: topology_00
+	#[test]
+	fn topology_00() {
+		let board: Board = board_from_rows(&["#."]);
+
+		assert_eq!(board.topology, Topology::Torus);
+		assert_eq!(board.with_topology(Topology::Bounded).topology, Topology::Bounded);
+	}
+	// endregion
+
+	// region: topology_01
+	#[test]
+	fn topology_01() {
+		let glider: [&str; 6] = ["......", "......", "......", "...#..", "....#.", "..###."];
+		let mut torus: Board = board_from_rows(&glider);
+		let mut bounded: Board = board_from_rows(&glider).with_topology(Topology::Bounded);
+
+		for _ in 0..4 {
+			torus.step();
+			bounded.step();
+		}
+		assert_eq!(
+			torus.cells(),
+			board_from_rows(&["...###", "......", "......", "......", "....#.", ".....#"]).cells()
+		);
+		assert_eq!(
+			bounded.cells(),
+			board_from_rows(&["......", "......", "......", "......", "...##.", "...##."]).cells()
+		);
+	}
+	// endregion
+
+	// region: topology_02
+	#[test]
+	fn topology_02() {
+		let glider: [&str; 6] = ["......", "......", "......", "...#..", "....#.", "..###."];
+		let mut torus: Board = board_from_rows(&glider);
+		let mut bounded: Board = board_from_rows(&glider).with_topology(Topology::Bounded);
+
+		for _ in 0..24 {
+			torus.step();
+			bounded.step();
+		}
+		assert_eq!(torus.cells(), board_from_rows(&glider).cells());
+		assert_eq!(
+			bounded.cells(),
+			board_from_rows(&["......", "......", "......", "......", "...##.", "...##."]).cells()
+		);
+	}
+	// endregion
+
+	// region: topology_03
+	#[test]
+	fn topology_03() {
+		let mut board: Board = board_from_rows(&["#....", "#....", "#....", ".....", "....."])
+			.with_topology(Topology::Bounded);
+
+		board.step();
+		assert_eq!(
+			board.cells(),
+			board_from_rows(&[".....", "##...", ".....", ".....", "....."]).cells()
+		);
+	}
+	// endregion
+
+	// region: topology_04
+	#[test]
+	fn topology_04() {
+		let mut board: Board =
+			Board::from_cells(1, 1, vec![Cell::Alive]).with_topology(Topology::Bounded);
+
+		board.step();
+		assert_eq!(board.cells(), [Cell::Dead]);
+
+		let mut board: Board = Board::from_cells(0, 0, vec![]).with_topology(Topology::Bounded);
+
+		board.step();
+		assert_eq!(board.cells(), []);
+	}
+	// endregion
+
+	// region: alive_count_00
+	#[test]
+	fn alive_count_00() {
+		assert_eq!(Board::from_cells(0, 0, vec![]).alive_count(), 0);
+		assert_eq!(board_from_rows(&["...", "..."]).alive_count(), 0);
+	}
+	// endregion
+
+	// region: alive_count_01
+	#[test]
+	fn alive_count_01() {
+		assert_eq!(board_from_rows(&["#.#", ".#."]).alive_count(), 3);
+		assert_eq!(board_from_rows(&["###", "###"]).alive_count(), 6);
+	}
+	// endregion
+
+	// region: alive_count_02
+	#[test]
+	fn alive_count_02() {
+		for percentage in [0, 10, 42, 100] {
+			assert_eq!(
+				Board::with_seed(17, 13, percentage, 0x2a).alive_count(),
+				percentage as usize * 17 * 13 / 100
+			);
+		}
+	}
+	// endregion
+
+	// region: step_detect_00
+	#[test]
+	fn step_detect_00() {
+		let mut board: Board = board_from_rows(&["....", ".##.", ".##.", "...."]);
+
+		for _ in 0..4 {
+			assert_eq!(board.step_detect(), StepOutcome::Unchanged);
+		}
+		assert_eq!(board.alive_count(), 4);
+	}
+	// endregion
+
+	// region: step_detect_01
+	#[test]
+	fn step_detect_01() {
+		let mut board: Board = board_from_rows(&["....", "....", "....", "...."]);
+
+		assert_eq!(board.step_detect(), StepOutcome::Extinct);
+		assert_eq!(board.step_detect(), StepOutcome::Extinct);
+	}
+	// endregion
+
+	// region: step_detect_02
+	#[test]
+	fn step_detect_02() {
+		let mut board: Board = board_from_rows(&[".....", ".....", ".###.", ".....", "....."]);
+
+		for _ in 0..4 {
+			assert_eq!(board.step_detect(), StepOutcome::Changed);
+		}
+	}
+	// endregion
+
+	// region: step_detect_03
+	#[test]
+	fn step_detect_03() {
+		let mut board: Board = board_from_rows(&["....", ".#..", "..#.", "...."]);
+
+		assert_eq!(board.step_detect(), StepOutcome::Extinct);
+	}
+	// endregion
+
+	// region: step_detect_04
+	#[test]
+	fn step_detect_04() {
+		let mut board: Board = board_from_rows(&["....", ".##.", ".#..", "...."]);
+
+		assert_eq!(board.step_detect(), StepOutcome::Changed);
+		assert_eq!(board.step_detect(), StepOutcome::Unchanged);
+	}
+	// endregion
+}
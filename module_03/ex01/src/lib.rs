@@ -28,6 +28,132 @@ pub fn min<T: PartialOrd>(a: T, b: T) -> T {
 	}
 }
 
+/// Finds the lowest thing of a slice.
+///
+/// ### Type parameters
+/// * `T` - The type of the values to compare.
+///
+/// ### Parameters
+/// * `slice` - The things to compare.
+///
+/// ### Return
+/// * `Some(T)` - The first occurrence of the lowest thing of `slice`.
+/// * `None` - `slice` is empty.
+///
+/// ### Example
+/// ```
+/// use::ex01::min_of;
+///
+/// assert_eq!(min_of(&[3, 1, 2]), Some(1));
+/// assert_eq!(min_of::<u8>(&[]), None);
+/// ```
+pub fn min_of<T: PartialOrd + Copy>(slice: &[T]) -> Option<T> {
+	slice.iter().copied().reduce(|lowest: T, value: T| min(value, lowest))
+}
+
+/// Finds the position of the lowest thing of a slice.
+///
+/// ### Type parameters
+/// * `T` - The type of the values to compare.
+///
+/// ### Parameters
+/// * `slice` - The things to compare.
+///
+/// ### Return
+/// * `Some(usize)` - The index of the first occurrence of the lowest thing of `slice`.
+/// * `None` - `slice` is empty.
+///
+/// ### Example
+/// ```
+/// use::ex01::argmin;
+///
+/// assert_eq!(argmin(&[3, 1, 2, 1]), Some(1));
+/// assert_eq!(argmin::<u8>(&[]), None);
+/// ```
+pub fn argmin<T: PartialOrd>(slice: &[T]) -> Option<usize> {
+	if slice.is_empty() {
+		return None;
+	}
+
+	let mut lowest: usize = 0;
+
+	for (i, value) in slice.iter().enumerate().skip(1) {
+		if *value < slice[lowest] {
+			lowest = i;
+		}
+	}
+
+	Some(lowest)
+}
+
+/// Compares two things and returns the greatest one.
+///
+/// ### Type parameters
+/// * `T` - The type of the two values to compare.
+///
+/// ### Parameters
+/// * `a` - The first thing to compare.
+/// * `b` - The second thing to compare.
+///
+/// ### Return
+/// The greatest thing between `a` and `b`.
+///
+/// ### Example
+/// ```
+/// use::ex01::max;
+///
+/// const A: &str = "baba";
+/// const B: &str = "bababoï";
+///
+/// assert_eq!(max(A, B), B);
+/// assert_eq!(max(B, A), B);
+/// ```
+pub fn max<T: PartialOrd>(a: T, b: T) -> T {
+	if a > b {
+		a
+	} else {
+		b
+	}
+}
+
+/// Pins a thing into a range of things.
+///
+/// ### Type parameters
+/// * `T` - The type of the values to compare.
+///
+/// ### Parameters
+/// * `value` - The thing to pin.
+/// * `lo` - The lower bound of the range, inclusive.
+/// * `hi` - The upper bound of the range, inclusive.
+///
+/// ### Return
+/// * `lo` - `value` is lower than `lo`.
+/// * `hi` - `value` is greater than `hi`.
+/// * `value` - Otherwise.
+///
+/// ### Panic
+/// In debug builds, `lo` is greater than `hi`.
+///
+/// ### Example
+/// ```
+/// use::ex01::clamp;
+///
+/// assert_eq!(clamp(-5, 0, 10), 0);
+/// assert_eq!(clamp(5, 0, 10), 5);
+/// assert_eq!(clamp(15, 0, 10), 10);
+/// ```
+pub fn clamp<T: PartialOrd>(value: T, lo: T, hi: T) -> T {
+	debug_assert!(lo <= hi, "lo must not be greater than hi");
+
+	if value < lo {
+		lo
+	} else if value > hi {
+		hi
+	} else {
+		value
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -157,4 +283,187 @@ mod tests {
 		assert_eq!(min("abc".to_string(), "abcd".to_string()), "abc".to_string());
 	}
 	// endregion
+
+	// region: max_00
+	#[test]
+	fn max_00() {
+		assert_eq!(max(f32::INFINITY, f32::EPSILON), f32::INFINITY);
+	}
+	// endregion
+
+	// region: max_01
+	#[test]
+	fn max_01() {
+		assert_eq!(max(f64::NEG_INFINITY, f64::MIN), f64::MIN);
+	}
+	// endregion
+
+	// region: max_02
+	#[test]
+	fn max_02() {
+		assert_eq!(max(-0.5f64, 0.25f64), 0.25f64);
+	}
+	// endregion
+
+	// region: max_03
+	#[test]
+	fn max_03() {
+		assert_eq!(max('a', 'z'), 'z');
+	}
+	// endregion
+
+	// region: max_04
+	#[test]
+	fn max_04() {
+		assert_eq!(max("Hello", "World!"), "World!");
+	}
+	// endregion
+
+	// region: max_05
+	#[test]
+	fn max_05() {
+		assert_eq!(max("abc".to_string(), "abcd".to_string()), "abcd".to_string());
+	}
+	// endregion
+
+	// region: max_06
+	#[test]
+	fn max_06() {
+		assert_eq!(max(i64::MIN, i64::MAX), i64::MAX);
+	}
+	// endregion
+
+	// region: clamp_00
+	#[test]
+	fn clamp_00() {
+		assert_eq!(clamp(-42i32, -21i32, 21i32), -21i32);
+		assert_eq!(clamp(0i32, -21i32, 21i32), 0i32);
+		assert_eq!(clamp(42i32, -21i32, 21i32), 21i32);
+	}
+	// endregion
+
+	// region: clamp_01
+	#[test]
+	fn clamp_01() {
+		assert_eq!(clamp(-1.5f64, 0.0f64, 1.0f64), 0.0f64);
+		assert_eq!(clamp(0.5f64, 0.0f64, 1.0f64), 0.5f64);
+		assert_eq!(clamp(f64::INFINITY, 0.0f64, 1.0f64), 1.0f64);
+	}
+	// endregion
+
+	// region: clamp_02
+	#[test]
+	fn clamp_02() {
+		assert_eq!(clamp("apple", "banana", "cherry"), "banana");
+		assert_eq!(clamp("blueberry", "banana", "cherry"), "blueberry");
+		assert_eq!(clamp("date", "banana", "cherry"), "cherry");
+	}
+	// endregion
+
+	// region: clamp_03
+	#[test]
+	fn clamp_03() {
+		assert_eq!(clamp(0u8, 0u8, 0u8), 0u8);
+		assert_eq!(clamp(u8::MAX, 42u8, 42u8), 42u8);
+	}
+	// endregion
+
+	// region: clamp_04
+	#[test]
+	#[cfg(debug_assertions)]
+	#[should_panic]
+	fn clamp_04() {
+		clamp(0u8, 2u8, 1u8);
+	}
+	// endregion
+
+	// region: min_of_00
+	#[test]
+	fn min_of_00() {
+		assert_eq!(min_of::<i32>(&[]), None);
+	}
+	// endregion
+
+	// region: min_of_01
+	#[test]
+	fn min_of_01() {
+		assert_eq!(min_of(&[42u8]), Some(42u8));
+	}
+	// endregion
+
+	// region: min_of_02
+	#[test]
+	fn min_of_02() {
+		assert_eq!(min_of(&[3i32, -1i32, 2i32, -1i32, 0i32]), Some(-1i32));
+	}
+	// endregion
+
+	// region: min_of_03
+	#[test]
+	fn min_of_03() {
+		assert_eq!(min_of(&[f64::INFINITY, 0.5f64, f64::MIN, 1.0f64]), Some(f64::MIN));
+	}
+	// endregion
+
+	// region: min_of_04
+	#[test]
+	fn min_of_04() {
+		assert_eq!(min_of(&["World!", "Hello", "Wololo"]), Some("Hello"));
+	}
+	// endregion
+
+	// region: min_of_05
+	#[test]
+	fn min_of_05() {
+		let lowest: f64 = min_of(&[1.0f64, 0.0f64, -0.0f64]).unwrap();
+
+		assert!(lowest.is_sign_positive());
+		assert_eq!(argmin(&[1.0f64, 0.0f64, -0.0f64]), Some(1));
+	}
+	// endregion
+
+	// region: min_of_06
+	#[test]
+	fn min_of_06() {
+		let lowest: f64 = min_of(&[-0.0f64, 0.0f64, 1.0f64]).unwrap();
+
+		assert!(lowest.is_sign_negative());
+		assert_eq!(argmin(&[-0.0f64, 0.0f64, 1.0f64]), Some(0));
+	}
+	// endregion
+
+	// region: argmin_00
+	#[test]
+	fn argmin_00() {
+		assert_eq!(argmin::<i32>(&[]), None);
+	}
+	// endregion
+
+	// region: argmin_01
+	#[test]
+	fn argmin_01() {
+		assert_eq!(argmin(&[42u8]), Some(0));
+	}
+	// endregion
+
+	// region: argmin_02
+	#[test]
+	fn argmin_02() {
+		assert_eq!(argmin(&[3i32, -1i32, 2i32, -1i32, 0i32]), Some(1));
+	}
+	// endregion
+
+	// region: argmin_03
+	#[test]
+	fn argmin_03() {
+		assert_eq!(argmin(&[7u32, 7u32, 7u32]), Some(0));
+	}
+	// endregion
+
+	// region: argmin_04
+	#[test]
+	fn argmin_04() {
+		assert_eq!(argmin(&["abcd".to_string(), "abd".to_string(), "abc".to_string()]), Some(2));
+	}
+	// endregion
 }
@@ -1,5 +1,6 @@
 use std::fmt;
 use std::fmt::{Debug, Display, Formatter};
+use std::ops::Sub;
 
 #[derive(PartialEq)]
 enum TimeParseError {
@@ -36,12 +37,49 @@ impl Display for TimeParseError {
 	}
 }
 
-#[derive(PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
 struct Time {
 	hours: u32,
 	minutes: u32,
 }
 
+impl Time {
+	const MINUTES_PER_DAY: i64 = 24 * 60;
+
+	/// ### Return
+	/// The number of minutes elapsed since midnight.
+	fn minutes_since_midnight(self: &Self) -> i64 {
+		(self.hours * 60 + self.minutes) as i64
+	}
+
+	/// Adds a number of minutes to a time, wrapping around a 24-hour clock.
+	///
+	/// ### Parameters
+	/// * `m` - The number of minutes to add, which may be negative.
+	///
+	/// ### Return
+	/// The resulting time.
+	pub fn add_minutes(self: &Self, m: i64) -> Time {
+		let minutes: i64 = (self.minutes_since_midnight() + m.rem_euclid(Self::MINUTES_PER_DAY))
+			% Self::MINUTES_PER_DAY;
+
+		Time { hours: (minutes / 60) as u32, minutes: (minutes % 60) as u32 }
+	}
+}
+
+impl Sub for Time {
+	type Output = i64;
+
+	/// ### Parameters
+	/// * `rhs` - The time to subtract from the calling one.
+	///
+	/// ### Return
+	/// The signed difference between both times, in minutes.
+	fn sub(self: Self, rhs: Self) -> Self::Output {
+		self.minutes_since_midnight() - rhs.minutes_since_midnight()
+	}
+}
+
 impl std::str::FromStr for Time {
 	type Err = TimeParseError;
 
@@ -105,7 +143,7 @@ impl Debug for Time {
 
 impl Display for Time {
 	fn fmt(self: &Self, formatter: &mut Formatter<'_>) -> fmt::Result {
-		write!(formatter, "{} hours, {} minutes", self.hours, self.minutes)
+		write!(formatter, "{:02}:{:02}", self.hours, self.minutes)
 	}
 }
 
@@ -202,4 +240,102 @@ fn main() {
 		}
 	}
 	// endregion
+
+	println!();
+
+	// region: Test display
+	{
+		let padding: usize = 24;
+		let tests: [(Time, &str); 6] = [
+			// region: tests
+			(Time { hours: 0, minutes: 0 }, "00:00"),
+			(Time { hours: 9, minutes: 5 }, "09:05"),
+			(Time { hours: 9, minutes: 42 }, "09:42"),
+			(Time { hours: 12, minutes: 3 }, "12:03"),
+			(Time { hours: 23, minutes: 59 }, "23:59"),
+			(Time { hours: 21, minutes: 42 }, "21:42"),
+			// endregion
+		];
+
+		println!("\tDisplay:");
+		for test in tests {
+			println!(
+				"\t\t{:>padding$}: {}",
+				format!("{:?}", test.0),
+				if test.0.to_string() == test.1 && test.1.parse::<Time>() == Ok(test.0) {
+					format!("{GREEN}[OK]{RESET}")
+				} else {
+					format!("{RED}[KO]{RESET}")
+				},
+				padding = padding,
+			);
+		}
+	}
+	// endregion
+
+	println!();
+
+	// region: Test minutes addition
+	{
+		let padding: usize = 16;
+		let tests: [(&str, i64, Time); 10] = [
+			// region: tests
+			("12:34", 0, Time { hours: 12, minutes: 34 }),
+			("12:34", 26, Time { hours: 13, minutes: 0 }),
+			("23:30", 60, Time { hours: 0, minutes: 30 }),
+			("23:59", 1, Time { hours: 0, minutes: 0 }),
+			("00:30", -60, Time { hours: 23, minutes: 30 }),
+			("00:00", -1, Time { hours: 23, minutes: 59 }),
+			("21:42", 1440, Time { hours: 21, minutes: 42 }),
+			("21:42", -1440 * 3, Time { hours: 21, minutes: 42 }),
+			("01:00", -1440 * 2 - 61, Time { hours: 23, minutes: 59 }),
+			("00:00", i64::MIN, Time { hours: 5, minutes: 52 }),
+			// endregion
+		];
+
+		println!("\tMinutes addition:");
+		for test in tests {
+			println!(
+				"\t\t{:>padding$}: {}",
+				format!("\"{}\" {:+}", test.0, test.1),
+				if test.0.parse::<Time>().map(|time: Time| time.add_minutes(test.1)) == Ok(test.2) {
+					format!("{GREEN}[OK]{RESET}")
+				} else {
+					format!("{RED}[KO]{RESET}")
+				},
+				padding = padding,
+			);
+		}
+	}
+	// endregion
+
+	println!();
+
+	// region: Test subtraction
+	{
+		let padding: usize = 16;
+		let tests: [(&str, &str, i64); 5] = [
+			// region: tests
+			("12:34", "12:34", 0),
+			("00:00", "00:00", 0),
+			("13:00", "12:34", 26),
+			("12:34", "13:00", -26),
+			("23:59", "00:00", 1439),
+			// endregion
+		];
+
+		println!("\tSubtraction:");
+		for test in tests {
+			println!(
+				"\t\t{:>padding$}: {}",
+				format!("\"{}\" - \"{}\"", test.0, test.1),
+				match (test.0.parse::<Time>(), test.1.parse::<Time>()) {
+					(Ok(lhs), Ok(rhs)) if lhs - rhs == test.2 => format!("{GREEN}[OK]{RESET}"),
+					_ => format!("{RED}[KO]{RESET}"),
+				},
+				padding = padding,
+			);
+		}
+	}
+	// endregion
 }
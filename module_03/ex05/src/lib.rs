@@ -1,4 +1,4 @@
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Vector<T> {
@@ -26,6 +26,85 @@ impl<T> Vector<T> {
 	pub const fn new(x: T, y: T) -> Self {
 		Self { x, y }
 	}
+
+	/// Calculates the dot product of the vector with another one.
+	///
+	/// ### Parameters
+	/// * `rhs` - The other vector to calculate the dot product with.
+	///
+	/// ### Return
+	/// The calculated dot product.
+	///
+	/// ### Example
+	/// ```
+	/// use ex05::Vector;
+	///
+	/// let v0: Vector<i32> = Vector::new(1, 2);
+	/// let v1: Vector<i32> = Vector::new(3, 4);
+	///
+	/// assert_eq!(v0.dot(v1), 11);
+	/// ```
+	#[inline(always)]
+	pub fn dot(self: Self, rhs: Self) -> T
+	where
+		T: Mul<Output = T> + Add<Output = T>,
+	{
+		self.x * rhs.x + self.y * rhs.y
+	}
+
+	/// Calculates the cross product of the vector with another one,
+	/// that is the z component of the cross product of the two vectors in 3D.
+	///
+	/// ### Parameters
+	/// * `rhs` - The other vector to calculate the cross product with.
+	///
+	/// ### Return
+	/// The calculated cross product.
+	///
+	/// ### Example
+	/// ```
+	/// use ex05::Vector;
+	///
+	/// let v0: Vector<i32> = Vector::new(1, 2);
+	/// let v1: Vector<i32> = Vector::new(3, 4);
+	///
+	/// assert_eq!(v0.cross(v1), -2);
+	/// ```
+	#[inline(always)]
+	pub fn cross(self: Self, rhs: Self) -> T
+	where
+		T: Mul<Output = T> + Sub<Output = T>,
+	{
+		self.x * rhs.y - self.y * rhs.x
+	}
+
+	/// Applies a function to both components of the vector.
+	///
+	/// ### Type parameters
+	/// * `U` - The type of the components of the resulting vector.
+	/// * `F` - The type of the function to apply.
+	///
+	/// ### Parameters
+	/// * `f` - The function to apply, first to the x component, then to the y component.
+	///
+	/// ### Return
+	/// The resulting vector.
+	///
+	/// ### Example
+	/// ```
+	/// use ex05::Vector;
+	///
+	/// let vector: Vector<f64> = Vector::<i32>::new(1, 2).map(|c| c as f64);
+	///
+	/// assert_eq!(vector, Vector::new(1.0, 2.0));
+	/// ```
+	#[inline(always)]
+	pub fn map<U, F: FnMut(T) -> U>(self: Self, mut f: F) -> Vector<U> {
+		let x: U = f(self.x);
+		let y: U = f(self.y);
+
+		Vector::new(x, y)
+	}
 }
 
 impl Vector<f32> {
@@ -45,6 +124,72 @@ impl Vector<f32> {
 	pub fn length(self: &Self) -> f32 {
 		(self.x * self.x + self.y * self.y).sqrt()
 	}
+
+	/// Calculates the unit vector that has the same direction as the vector.
+	/// Normalizing a zero vector results in a vector whose components are `NaN`.
+	///
+	/// ### Return
+	/// The calculated unit vector.
+	///
+	/// ### Example
+	/// ```
+	/// use ex05::Vector;
+	///
+	/// let vector: Vector<f32> = Vector::new(3.0, 4.0);
+	/// assert_eq!(vector.normalized(), Vector::new(0.6, 0.8));
+	/// ```
+	#[inline(always)]
+	pub fn normalized(self: Self) -> Self {
+		self / self.length()
+	}
+
+	/// Calculates the signed angle from the vector to another one.
+	///
+	/// ### Parameters
+	/// * `other` - The other vector to calculate the angle with.
+	///
+	/// ### Return
+	/// The calculated angle, in radians, in the range [-π, π].
+	///
+	/// ### Example
+	/// ```
+	/// use ex05::Vector;
+	///
+	/// let v0: Vector<f32> = Vector::new(1.0, 0.0);
+	/// let v1: Vector<f32> = Vector::new(0.0, 2.0);
+	///
+	/// assert_eq!(v0.angle_between(v1), std::f32::consts::FRAC_PI_2);
+	/// assert_eq!(v0.angle_between(v0), 0.0);
+	/// ```
+	#[inline(always)]
+	pub fn angle_between(self: Self, other: Self) -> f32 {
+		self.cross(other).atan2(self.dot(other))
+	}
+
+	/// Calculates the vector that has the same direction as the vector and a given length.
+	/// Scaling a zero vector results in a zero vector.
+	///
+	/// ### Parameters
+	/// * `target_len` - The length of the resulting vector.
+	///
+	/// ### Return
+	/// The calculated vector.
+	///
+	/// ### Example
+	/// ```
+	/// use ex05::Vector;
+	///
+	/// let vector: Vector<f32> = Vector::new(3.0, 4.0);
+	/// assert_eq!(vector.scale_to(10.0), Vector::new(6.0, 8.0));
+	/// ```
+	#[inline(always)]
+	pub fn scale_to(self: Self, target_len: f32) -> Self {
+		if self.x == 0.0 && self.y == 0.0 {
+			return Self::new(0.0, 0.0);
+		}
+
+		self.normalized() * target_len
+	}
 }
 
 impl Vector<f64> {
@@ -64,8 +209,132 @@ impl Vector<f64> {
 	pub fn length(self: &Self) -> f64 {
 		(self.x * self.x + self.y * self.y).sqrt()
 	}
+
+	/// Calculates the unit vector that has the same direction as the vector.
+	/// Normalizing a zero vector results in a vector whose components are `NaN`.
+	///
+	/// ### Return
+	/// The calculated unit vector.
+	///
+	/// ### Example
+	/// ```
+	/// use ex05::Vector;
+	///
+	/// let vector: Vector<f64> = Vector::new(3.0, 4.0);
+	/// assert_eq!(vector.normalized(), Vector::new(0.6, 0.8));
+	/// ```
+	#[inline(always)]
+	pub fn normalized(self: Self) -> Self {
+		self / self.length()
+	}
+
+	/// Calculates the signed angle from the vector to another one.
+	///
+	/// ### Parameters
+	/// * `other` - The other vector to calculate the angle with.
+	///
+	/// ### Return
+	/// The calculated angle, in radians, in the range [-π, π].
+	///
+	/// ### Example
+	/// ```
+	/// use ex05::Vector;
+	///
+	/// let v0: Vector<f64> = Vector::new(1.0, 0.0);
+	/// let v1: Vector<f64> = Vector::new(0.0, 2.0);
+	///
+	/// assert_eq!(v0.angle_between(v1), std::f64::consts::FRAC_PI_2);
+	/// assert_eq!(v0.angle_between(v0), 0.0);
+	/// ```
+	#[inline(always)]
+	pub fn angle_between(self: Self, other: Self) -> f64 {
+		self.cross(other).atan2(self.dot(other))
+	}
+
+	/// Calculates the vector that has the same direction as the vector and a given length.
+	/// Scaling a zero vector results in a zero vector.
+	///
+	/// ### Parameters
+	/// * `target_len` - The length of the resulting vector.
+	///
+	/// ### Return
+	/// The calculated vector.
+	///
+	/// ### Example
+	/// ```
+	/// use ex05::Vector;
+	///
+	/// let vector: Vector<f64> = Vector::new(3.0, 4.0);
+	/// assert_eq!(vector.scale_to(10.0), Vector::new(6.0, 8.0));
+	/// ```
+	#[inline(always)]
+	pub fn scale_to(self: Self, target_len: f64) -> Self {
+		if self.x == 0.0 && self.y == 0.0 {
+			return Self::new(0.0, 0.0);
+		}
+
+		self.normalized() * target_len
+	}
+}
+
+macro_rules! impl_manhattan_length_signed {
+	($($t:ty)*) => {$(
+		impl Vector<$t> {
+			/// Calculates the Manhattan length of the vector,
+			/// that is the sum of the absolute values of its components.
+			///
+			/// ### Return
+			/// The calculated Manhattan length of the vector.
+			///
+			/// ### Panic
+			/// In debug mode, the calculation panics on overflow,
+			/// e.g. if one of the components is the minimum value of its type.
+			///
+			/// ### Example
+			/// ```
+			/// use ex05::Vector;
+			///
+			/// let vector: Vector<i32> = Vector::new(-3, 4);
+			/// assert_eq!(vector.manhattan_length(), 7);
+			/// ```
+			#[inline(always)]
+			pub fn manhattan_length(self: Self) -> $t {
+				self.x.abs() + self.y.abs()
+			}
+		}
+	)*};
 }
 
+macro_rules! impl_manhattan_length_unsigned {
+	($($t:ty)*) => {$(
+		impl Vector<$t> {
+			/// Calculates the Manhattan length of the vector,
+			/// that is the sum of its components.
+			///
+			/// ### Return
+			/// The calculated Manhattan length of the vector.
+			///
+			/// ### Panic
+			/// In debug mode, the calculation panics on overflow.
+			///
+			/// ### Example
+			/// ```
+			/// use ex05::Vector;
+			///
+			/// let vector: Vector<u32> = Vector::new(3, 4);
+			/// assert_eq!(vector.manhattan_length(), 7);
+			/// ```
+			#[inline(always)]
+			pub fn manhattan_length(self: Self) -> $t {
+				self.x + self.y
+			}
+		}
+	)*};
+}
+
+impl_manhattan_length_signed!(i8 i16 i32 i64 i128 isize);
+impl_manhattan_length_unsigned!(u8 u16 u32 u64 u128 usize);
+
 impl<T> Add for Vector<T>
 where
 	T: Add<Output = T>,
@@ -158,6 +427,32 @@ where
 	}
 }
 
+impl<T> Neg for Vector<T>
+where
+	T: Neg<Output = T>,
+{
+	type Output = Self;
+
+	#[inline(always)]
+	fn neg(self: Self) -> Self::Output {
+		Self::new(-self.x, -self.y)
+	}
+}
+
+impl<T> From<(T, T)> for Vector<T> {
+	#[inline(always)]
+	fn from(tuple: (T, T)) -> Self {
+		Self::new(tuple.0, tuple.1)
+	}
+}
+
+impl<T> From<Vector<T>> for (T, T) {
+	#[inline(always)]
+	fn from(vector: Vector<T>) -> Self {
+		(vector.x, vector.y)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -384,6 +679,15 @@ mod tests {
 			self.n /= rhs.n;
 		}
 	}
+
+	impl Neg for C {
+		type Output = Self;
+
+		#[inline(always)]
+		fn neg(self: Self) -> Self::Output {
+			Self { n: -self.n }
+		}
+	}
 	// endregion
 
 	// region: test_function_new
@@ -483,6 +787,19 @@ mod tests {
 	}
 	// endregion
 
+	// region: test_operator_neg
+	#[inline(always)]
+	fn test_operator_neg<T>(x: T, y: T)
+	where
+		T: Neg<Output = T> + Copy + std::fmt::Debug + PartialEq,
+	{
+		let v: Vector<T> = Vector::new(x, y);
+		let expected: Vector<T> = Vector::new(-x, -y);
+
+		assert_eq!(-v, expected);
+	}
+	// endregion
+
 	// region: test_operator_add_assign
 	#[inline(always)]
 	fn test_operator_add_assign<T>(v0_x: T, v0_y: T, v1_x: T, v1_y: T)
@@ -574,6 +891,81 @@ mod tests {
 	}
 	// endregion
 
+	// region: test_function_dot
+	#[inline(always)]
+	fn test_function_dot<T>(v0_x: T, v0_y: T, v1_x: T, v1_y: T)
+	where
+		T: Add<Output = T> + Mul<Output = T> + Copy + std::fmt::Debug + PartialEq,
+	{
+		let v0: Vector<T> = Vector::new(v0_x, v0_y);
+		let v1: Vector<T> = Vector::new(v1_x, v1_y);
+		let expected: T = v0_x * v1_x + v0_y * v1_y;
+
+		assert_eq!(v0.dot(v1), expected);
+		assert_eq!(v1.dot(v0), expected);
+	}
+	// endregion
+
+	// region: test_function_cross
+	#[inline(always)]
+	fn test_function_cross<T>(lhs_x: T, lhs_y: T, rhs_x: T, rhs_y: T)
+	where
+		T: Sub<Output = T> + Mul<Output = T> + Copy + std::fmt::Debug + PartialEq,
+	{
+		let lhs: Vector<T> = Vector::new(lhs_x, lhs_y);
+		let rhs: Vector<T> = Vector::new(rhs_x, rhs_y);
+		let expected: T = lhs_x * rhs_y - lhs_y * rhs_x;
+
+		assert_eq!(lhs.cross(rhs), expected);
+	}
+	// endregion
+
+	// region: test_function_normalized_f32
+	#[inline(always)]
+	fn test_function_normalized_f32(x: f32, y: f32) {
+		let v: Vector<f32> = Vector::<f32>::new(x, y).normalized();
+
+		assert!((v.length() - 1.0).abs() <= f32::EPSILON);
+		assert!((v.x * y - v.y * x).abs() <= f32::EPSILON * (x.abs() + y.abs()));
+	}
+	// endregion
+
+	// region: test_function_normalized_f64
+	#[inline(always)]
+	fn test_function_normalized_f64(x: f64, y: f64) {
+		let v: Vector<f64> = Vector::<f64>::new(x, y).normalized();
+
+		assert!((v.length() - 1.0).abs() <= f64::EPSILON);
+		assert!((v.x * y - v.y * x).abs() <= f64::EPSILON * (x.abs() + y.abs()));
+	}
+	// endregion
+
+	// region: test_function_scale_to_f32
+	fn test_function_scale_to_f32(x: f32, y: f32, target_len: f32) {
+		let v: Vector<f32> = Vector::<f32>::new(x, y).scale_to(target_len);
+
+		assert!((v.length() - target_len.abs()).abs() <= f32::EPSILON * 4.0 * target_len.abs());
+		assert!(
+			(v.x * y - v.y * x).abs()
+				<= f32::EPSILON * 4.0 * target_len.abs() * (x.abs() + y.abs())
+		);
+		assert!(v.dot(Vector::new(x, y)) * target_len >= 0.0);
+	}
+	// endregion
+
+	// region: test_function_scale_to_f64
+	fn test_function_scale_to_f64(x: f64, y: f64, target_len: f64) {
+		let v: Vector<f64> = Vector::<f64>::new(x, y).scale_to(target_len);
+
+		assert!((v.length() - target_len.abs()).abs() <= f64::EPSILON * 4.0 * target_len.abs());
+		assert!(
+			(v.x * y - v.y * x).abs()
+				<= f64::EPSILON * 4.0 * target_len.abs() * (x.abs() + y.abs())
+		);
+		assert!(v.dot(Vector::new(x, y)) * target_len >= 0.0);
+	}
+	// endregion
+
 	// region: new_00
 	#[test]
 	fn new_00() {
@@ -784,6 +1176,28 @@ mod tests {
 	}
 	// endregion
 
+	// region: operator_neg_00
+	#[test]
+	fn operator_neg_00() {
+		test_operator_neg(C::new(-47), C::new(93));
+	}
+	// endregion
+
+	// region: operator_neg_01
+	#[test]
+	fn operator_neg_01() {
+		test_operator_neg(0i32, i32::MAX);
+	}
+	// endregion
+
+	// region: operator_neg_02
+	#[test]
+	fn operator_neg_02() {
+		test_operator_neg(1.0f64, -2.0f64);
+		assert_eq!(-Vector::new(1.0f64, -2.0f64), Vector::new(-1.0f64, 2.0f64));
+	}
+	// endregion
+
 	// region: operator_add_assign_00
 	#[test]
 	fn operator_add_assign_00() {
@@ -938,6 +1352,313 @@ mod tests {
 	}
 	// endregion
 
+	// region: function_dot_00
+	#[test]
+	fn function_dot_00() {
+		test_function_dot(B::new(0x02), B::new(0x03), B::new(0x04), B::new(0x05));
+	}
+	// endregion
+
+	// region: function_dot_01
+	#[test]
+	fn function_dot_01() {
+		test_function_dot(C::new(-3), C::new(7), C::new(5), C::new(-2));
+	}
+	// endregion
+
+	// region: function_dot_02
+	#[test]
+	fn function_dot_02() {
+		test_function_dot(1i32, 0i32, 0i32, 1i32);
+		assert_eq!(Vector::new(1i32, 0i32).dot(Vector::new(0i32, 1i32)), 0i32);
+	}
+	// endregion
+
+	// region: function_dot_03
+	#[test]
+	fn function_dot_03() {
+		test_function_dot(1.5f32, -2.0f32, 4.0f32, 0.25f32);
+		assert_eq!(Vector::new(1.0f32, 0.0f32).dot(Vector::new(0.0f32, 1.0f32)), 0.0f32);
+	}
+	// endregion
+
+	// region: function_cross_00
+	#[test]
+	fn function_cross_00() {
+		test_function_cross(C::new(-3), C::new(7), C::new(5), C::new(-2));
+	}
+	// endregion
+
+	// region: function_cross_01
+	#[test]
+	fn function_cross_01() {
+		test_function_cross(1i32, 0i32, 0i32, 1i32);
+		assert_eq!(Vector::new(1i32, 0i32).cross(Vector::new(0i32, 1i32)), 1i32);
+		assert_eq!(Vector::new(0i32, 1i32).cross(Vector::new(1i32, 0i32)), -1i32);
+	}
+	// endregion
+
+	// region: function_cross_02
+	#[test]
+	fn function_cross_02() {
+		test_function_cross(1.5f32, -2.0f32, 4.0f32, 0.25f32);
+		assert_eq!(Vector::new(2.0f32, 4.0f32).cross(Vector::new(1.0f32, 2.0f32)), 0.0f32);
+	}
+	// endregion
+
+	// region: function_normalized_00
+	#[test]
+	fn function_normalized_00() {
+		test_function_normalized_f32(3.0, 4.0);
+	}
+	// endregion
+
+	// region: function_normalized_01
+	#[test]
+	fn function_normalized_01() {
+		test_function_normalized_f32(-12.0, 7.0);
+	}
+	// endregion
+
+	// region: function_normalized_02
+	#[test]
+	fn function_normalized_02() {
+		let v: Vector<f32> = Vector::<f32>::new(0.0, 0.0).normalized();
+
+		assert!(v.x.is_nan());
+		assert!(v.y.is_nan());
+	}
+	// endregion
+
+	// region: function_normalized_03
+	#[test]
+	fn function_normalized_03() {
+		test_function_normalized_f64(3.0, 4.0);
+	}
+	// endregion
+
+	// region: function_normalized_04
+	#[test]
+	fn function_normalized_04() {
+		test_function_normalized_f64(-12.0, 7.0);
+	}
+	// endregion
+
+	// region: function_normalized_05
+	#[test]
+	fn function_normalized_05() {
+		let v: Vector<f64> = Vector::<f64>::new(0.0, 0.0).normalized();
+
+		assert!(v.x.is_nan());
+		assert!(v.y.is_nan());
+	}
+	// endregion
+
+	// region: function_angle_between_00
+	#[test]
+	fn function_angle_between_00() {
+		let v: Vector<f32> = Vector::new(3.0, -4.0);
+
+		assert_eq!(v.angle_between(v), 0.0);
+	}
+	// endregion
+
+	// region: function_angle_between_01
+	#[test]
+	fn function_angle_between_01() {
+		let v0: Vector<f32> = Vector::new(1.0, 0.0);
+		let v1: Vector<f32> = Vector::new(0.0, 1.0);
+
+		assert_eq!(v0.angle_between(v1), std::f32::consts::FRAC_PI_2);
+		assert_eq!(v1.angle_between(v0), -std::f32::consts::FRAC_PI_2);
+	}
+	// endregion
+
+	// region: function_angle_between_02
+	#[test]
+	fn function_angle_between_02() {
+		let v: Vector<f64> = Vector::new(-1.5, 2.5);
+
+		assert_eq!(v.angle_between(v), 0.0);
+	}
+	// endregion
+
+	// region: function_angle_between_03
+	#[test]
+	fn function_angle_between_03() {
+		let v0: Vector<f64> = Vector::new(2.0, 2.0);
+		let v1: Vector<f64> = Vector::new(-3.0, -3.0);
+
+		assert_eq!(v0.angle_between(v1), std::f64::consts::PI);
+	}
+	// endregion
+
+	// region: function_scale_to_00
+	#[test]
+	fn function_scale_to_00() {
+		test_function_scale_to_f32(3.0, 4.0, 10.0);
+	}
+	// endregion
+
+	// region: function_scale_to_01
+	#[test]
+	fn function_scale_to_01() {
+		test_function_scale_to_f32(-12.0, 7.0, 0.5);
+	}
+	// endregion
+
+	// region: function_scale_to_02
+	#[test]
+	fn function_scale_to_02() {
+		let v: Vector<f32> = Vector::<f32>::new(0.0, 0.0).scale_to(10.0);
+
+		assert_eq!(v, Vector::new(0.0, 0.0));
+	}
+	// endregion
+
+	// region: function_scale_to_03
+	#[test]
+	fn function_scale_to_03() {
+		test_function_scale_to_f64(3.0, 4.0, 10.0);
+	}
+	// endregion
+
+	// region: function_scale_to_04
+	#[test]
+	fn function_scale_to_04() {
+		test_function_scale_to_f64(-12.0, 7.0, 0.5);
+	}
+	// endregion
+
+	// region: function_scale_to_05
+	#[test]
+	fn function_scale_to_05() {
+		let v: Vector<f64> = Vector::<f64>::new(0.0, 0.0).scale_to(10.0);
+
+		assert_eq!(v, Vector::new(0.0, 0.0));
+	}
+	// endregion
+
+	// region: function_scale_to_06
+	#[test]
+	fn function_scale_to_06() {
+		test_function_scale_to_f64(1.0, -1.0, -2.0);
+	}
+	// endregion
+
+	// region: function_map_00
+	#[test]
+	fn function_map_00() {
+		let v: Vector<f64> = Vector::<i32>::new(1, 2).map(|c| c as f64);
+
+		assert_eq!(v, Vector::new(1.0, 2.0));
+	}
+	// endregion
+
+	// region: function_map_01
+	#[test]
+	fn function_map_01() {
+		let mut order: Vec<char> = Vec::new();
+		let v: Vector<u32> = Vector::new('a', 'b').map(|c| {
+			order.push(c);
+			c as u32
+		});
+
+		assert_eq!(v, Vector::new(0x61, 0x62));
+		assert_eq!(order, ['a', 'b']);
+	}
+	// endregion
+
+	// region: function_map_02
+	#[test]
+	fn function_map_02() {
+		let v: Vector<B> = Vector::new(C::new(-3), C::new(4)).map(|c| B::new(c.n.unsigned_abs()));
+
+		assert_eq!(v, Vector::new(B::new(3), B::new(4)));
+	}
+	// endregion
+
+	// region: function_manhattan_length_00
+	#[test]
+	fn function_manhattan_length_00() {
+		assert_eq!(Vector::<i32>::new(-3, 4).manhattan_length(), 7);
+	}
+	// endregion
+
+	// region: function_manhattan_length_01
+	#[test]
+	fn function_manhattan_length_01() {
+		assert_eq!(Vector::<i8>::new(0, 0).manhattan_length(), 0);
+		assert_eq!(Vector::<i64>::new(-12, -7).manhattan_length(), 19);
+		assert_eq!(Vector::<i128>::new(5, -5).manhattan_length(), 10);
+		assert_eq!(Vector::<isize>::new(-1, 0).manhattan_length(), 1);
+	}
+	// endregion
+
+	// region: function_manhattan_length_02
+	#[test]
+	fn function_manhattan_length_02() {
+		assert_eq!(Vector::<i8>::new(-63, 64).manhattan_length(), i8::MAX);
+		assert_eq!(Vector::<i16>::new(i16::MAX, 0).manhattan_length(), i16::MAX);
+	}
+	// endregion
+
+	// region: function_manhattan_length_03
+	#[test]
+	fn function_manhattan_length_03() {
+		assert_eq!(Vector::<u8>::new(0, 0).manhattan_length(), 0);
+		assert_eq!(Vector::<u32>::new(3, 4).manhattan_length(), 7);
+		assert_eq!(Vector::<u64>::new(12, 7).manhattan_length(), 19);
+		assert_eq!(Vector::<usize>::new(1, 0).manhattan_length(), 1);
+	}
+	// endregion
+
+	// region: function_manhattan_length_04
+	#[test]
+	#[cfg(debug_assertions)]
+	#[should_panic]
+	fn function_manhattan_length_04() {
+		Vector::<i32>::new(i32::MIN, 0).manhattan_length();
+	}
+	// endregion
+
+	// region: from_tuple_00
+	#[test]
+	fn from_tuple_00() {
+		let v: Vector<i8> = Vector::from((-1, 2));
+
+		assert_eq!(v, Vector::new(-1, 2));
+	}
+	// endregion
+
+	// region: from_tuple_01
+	#[test]
+	fn from_tuple_01() {
+		let v: Vector<&str> = ("Hello", "World").into();
+
+		assert_eq!(v, Vector::new("Hello", "World"));
+	}
+	// endregion
+
+	// region: into_tuple_00
+	#[test]
+	fn into_tuple_00() {
+		let tuple: (u16, u16) = Vector::new(42, 21).into();
+
+		assert_eq!(tuple, (42, 21));
+	}
+	// endregion
+
+	// region: into_tuple_01
+	#[test]
+	fn into_tuple_01() {
+		let v: Vector<C> = Vector::new(C::new(-128), C::new(127));
+		let tuple: (C, C) = v.into();
+
+		assert_eq!(Vector::from(tuple), v);
+	}
+	// endregion
+
 	// region: subject_00
 	#[test]
 	fn subject_00() {
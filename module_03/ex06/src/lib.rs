@@ -279,7 +279,283 @@ impl<T> List<T> {
 	/// list.clear();
 	/// ```
 	pub fn clear(self: &mut Self) {
-		self.head = None;
+		// Replacing the List instance drops its nodes iteratively, see `Drop`.
+		*self = Self::new();
+	}
+
+	/// ### Return
+	/// * `Some(&T)` - A reference to the greatest element of the calling List instance.
+	/// * `None` - The calling List instance is empty.
+	///
+	/// ### Example
+	/// ```
+	/// use ex06::List;
+	///
+	/// let list: List<u8> = [0x03, 0x01, 0x02].into_iter().collect();
+	///
+	/// assert_eq!(list.max(), Some(&0x03));
+	/// ```
+	pub fn max(self: &Self) -> Option<&T>
+	where
+		T: Ord,
+	{
+		let mut max: Option<&T> = None;
+		let mut current: &Option<Box<Node<T>>> = &self.head;
+
+		while let Some(node) = current {
+			if max.is_none_or(|max: &T| node.value > *max) {
+				max = Some(&node.value);
+			}
+			current = &node.next;
+		}
+
+		max
+	}
+
+	/// ### Return
+	/// * `Some(&T)` - A reference to the smallest element of the calling List instance.
+	/// * `None` - The calling List instance is empty.
+	///
+	/// ### Example
+	/// ```
+	/// use ex06::List;
+	///
+	/// let list: List<u8> = [0x03, 0x01, 0x02].into_iter().collect();
+	///
+	/// assert_eq!(list.min(), Some(&0x01));
+	/// ```
+	pub fn min(self: &Self) -> Option<&T>
+	where
+		T: Ord,
+	{
+		let mut min: Option<&T> = None;
+		let mut current: &Option<Box<Node<T>>> = &self.head;
+
+		while let Some(node) = current {
+			if min.is_none_or(|min: &T| node.value < *min) {
+				min = Some(&node.value);
+			}
+			current = &node.next;
+		}
+
+		min
+	}
+
+	/// Checks whether the calling List instance contains the same elements as another one,
+	/// the same number of times each, regardless of their order.
+	///
+	/// ### Parameters
+	/// * `other` - The List instance to compare the calling one with.
+	///
+	/// ### Return
+	/// * `true` - Both List instances contain the same elements.
+	/// * `false` - Both List instances do not contain the same elements.
+	///
+	/// ### Example
+	/// ```
+	/// use ex06::List;
+	///
+	/// let list0: List<u8> = [0x01, 0x02, 0x03].into_iter().collect();
+	/// let list1: List<u8> = [0x03, 0x01, 0x02].into_iter().collect();
+	/// let list2: List<u8> = [0x01, 0x02, 0x02].into_iter().collect();
+	///
+	/// assert!(list0.eq_unordered(&list1));
+	/// assert!(!list0.eq_unordered(&list2));
+	/// ```
+	pub fn eq_unordered(self: &Self, other: &List<T>) -> bool
+	where
+		T: Ord + Clone,
+	{
+		if self.count() != other.count() {
+			return false;
+		}
+
+		let mut values0: Vec<T> = Vec::with_capacity(self.count());
+		let mut values1: Vec<T> = Vec::with_capacity(other.count());
+		let mut current: &Option<Box<Node<T>>> = &self.head;
+
+		while let Some(node) = current {
+			values0.push(node.value.clone());
+			current = &node.next;
+		}
+		current = &other.head;
+		while let Some(node) = current {
+			values1.push(node.value.clone());
+			current = &node.next;
+		}
+		values0.sort();
+		values1.sort();
+
+		values0 == values1
+	}
+
+	/// Moves the first elements of the calling List instance to its end, preserving their order.
+	///
+	/// ### Parameters
+	/// * `n` - The number of elements to move, modulo the number of elements of the calling List instance.
+	///
+	/// ### Example
+	/// ```
+	/// use ex06::List;
+	///
+	/// let mut list: List<u8> = [0x01, 0x02, 0x03, 0x04].into_iter().collect();
+	///
+	/// list.rotate_left(1);
+	///
+	/// assert_eq!(list, [0x02, 0x03, 0x04, 0x01].into_iter().collect());
+	/// ```
+	pub fn rotate_left(self: &mut Self, n: usize) {
+		let count: usize = self.count();
+
+		if count == 0 {
+			return;
+		}
+
+		let n: usize = n % count;
+
+		if n == 0 {
+			return;
+		}
+
+		let mut front: Option<Box<Node<T>>> = self.head.take();
+		let mut current: &mut Option<Box<Node<T>>> = &mut front;
+
+		for _ in 0..n {
+			current = &mut current.as_mut().unwrap().next;
+		}
+
+		let mut back: Option<Box<Node<T>>> = current.take();
+		let mut current: &mut Option<Box<Node<T>>> = &mut back;
+
+		while let Some(node) = current {
+			current = &mut node.next;
+		}
+		*current = front;
+		self.head = back;
+	}
+
+	/// Moves all the elements of another List instance to the end of the calling List instance,
+	/// preserving their order. The other List instance is left empty.
+	///
+	/// ### Parameters
+	/// * `other` - The List instance to move the elements from.
+	///
+	/// ### Example
+	/// ```
+	/// use ex06::List;
+	///
+	/// let mut list0: List<u8> = [0x01, 0x02].into_iter().collect();
+	/// let mut list1: List<u8> = [0x03, 0x04].into_iter().collect();
+	///
+	/// list0.append(&mut list1);
+	///
+	/// assert_eq!(list0, [0x01, 0x02, 0x03, 0x04].into_iter().collect());
+	/// assert_eq!(list1.count(), 0);
+	/// ```
+	pub fn append(self: &mut Self, other: &mut List<T>) {
+		let mut current: &mut Option<Box<Node<T>>> = &mut self.head;
+
+		while let Some(node) = current {
+			current = &mut node.next;
+		}
+		*current = other.head.take();
+	}
+
+	/// Splits the calling List instance in two at a given index.
+	///
+	/// ### Parameters
+	/// * `at` - The index of the first element to move to the returned List instance.
+	///
+	/// ### Return
+	/// A new List instance containing the elements of the calling List instance
+	/// from index `at` onward. The first `at` elements are left in the calling List instance.
+	///
+	/// ### Panic
+	/// `at` is greater than the number of elements of the calling List instance.
+	///
+	/// ### Example
+	/// ```
+	/// use ex06::List;
+	///
+	/// let mut list0: List<u8> = [0x01, 0x02, 0x03, 0x04].into_iter().collect();
+	/// let list1: List<u8> = list0.split_off(1);
+	///
+	/// assert_eq!(list0, [0x01].into_iter().collect());
+	/// assert_eq!(list1, [0x02, 0x03, 0x04].into_iter().collect());
+	/// ```
+	pub fn split_off(self: &mut Self, at: usize) -> List<T> {
+		let mut current: &mut Option<Box<Node<T>>> = &mut self.head;
+
+		for i in 0..at {
+			match current {
+				Some(node) => current = &mut node.next,
+				None => panic!("tried to split at out of bound index {at} (count is {i})"),
+			}
+		}
+
+		List { head: current.take() }
+	}
+
+	/// Checks if the calling List instance contains a given value.
+	///
+	/// ### Parameters
+	/// * `value` - The value to search for.
+	///
+	/// ### Return
+	/// * `true` - At least one element of the calling List instance is equal to `value`.
+	/// * `false` - No element of the calling List instance is equal to `value`.
+	///
+	/// ### Example
+	/// ```
+	/// use ex06::List;
+	///
+	/// let list: List<u8> = [0x01, 0x02, 0x03].into_iter().collect();
+	///
+	/// assert!(list.contains(&0x02));
+	/// assert!(!list.contains(&0x04));
+	/// ```
+	#[inline(always)]
+	pub fn contains(self: &Self, value: &T) -> bool
+	where
+		T: PartialEq,
+	{
+		self.position(value).is_some()
+	}
+
+	/// Searches the calling List instance for a given value.
+	///
+	/// ### Parameters
+	/// * `value` - The value to search for.
+	///
+	/// ### Return
+	/// * `Some(usize)` - The index of the first element that is equal to `value`.
+	/// * `None` - No element of the calling List instance is equal to `value`.
+	///
+	/// ### Example
+	/// ```
+	/// use ex06::List;
+	///
+	/// let list: List<u8> = [0x01, 0x02, 0x02].into_iter().collect();
+	///
+	/// assert_eq!(list.position(&0x02), Some(1));
+	/// assert_eq!(list.position(&0x04), None);
+	/// ```
+	pub fn position(self: &Self, value: &T) -> Option<usize>
+	where
+		T: PartialEq,
+	{
+		let mut i: usize = 0;
+		let mut current: &Option<Box<Node<T>>> = &self.head;
+
+		while let Some(node) = current {
+			if node.value == *value {
+				return Some(i);
+			}
+			i += 1;
+			current = &node.next;
+		}
+
+		None
 	}
 }
 
@@ -349,6 +625,114 @@ impl<T> std::ops::IndexMut<usize> for List<T> {
 	}
 }
 
+impl<T> FromIterator<T> for List<T> {
+	/// Creates a new List instance from the elements of an iterator.
+	/// The first element yielded by the iterator becomes the head of the list.
+	///
+	/// ### Type parameters
+	/// * `I` - The type of the iterator to collect the elements from.
+	///
+	/// ### Parameters
+	/// * `iter` - The iterator to collect the elements from.
+	///
+	/// ### Return
+	/// The newly created List instance.
+	///
+	/// ### Example
+	/// ```
+	/// use ex06::List;
+	///
+	/// let list: List<u8> = (0x1c..0x1f).collect();
+	///
+	/// assert_eq!(list[0], 0x1c);
+	/// assert_eq!(list[1], 0x1d);
+	/// assert_eq!(list[2], 0x1e);
+	/// ```
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		let mut list: Self = Self::new();
+
+		list.extend(iter);
+
+		list
+	}
+}
+
+impl<T> Extend<T> for List<T> {
+	/// Appends the elements of an iterator at the end of the calling List instance,
+	/// preserving their order.
+	///
+	/// ### Type parameters
+	/// * `I` - The type of the iterator to take the elements from.
+	///
+	/// ### Parameters
+	/// * `iter` - The iterator to take the elements from.
+	///
+	/// ### Example
+	/// ```
+	/// use ex06::List;
+	///
+	/// let mut list: List<u8> = List::new();
+	///
+	/// list.push_back(0x1f);
+	/// list.extend([0x20, 0x21]);
+	///
+	/// assert_eq!(list[0], 0x1f);
+	/// assert_eq!(list[1], 0x20);
+	/// assert_eq!(list[2], 0x21);
+	/// ```
+	fn extend<I: IntoIterator<Item = T>>(self: &mut Self, iter: I) {
+		let mut current: &mut Option<Box<Node<T>>> = &mut self.head;
+
+		while let Some(node) = current {
+			current = &mut node.next;
+		}
+
+		for value in iter {
+			current = &mut current.insert(Box::new(Node::new(value, None))).next;
+		}
+	}
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for List<T> {
+	/// Formats the elements of the calling List instance as `[a, b, c]`.
+	///
+	/// ### Example
+	/// ```
+	/// use ex06::List;
+	///
+	/// let list: List<u8> = [0x01, 0x02, 0x03].into_iter().collect();
+	///
+	/// assert_eq!(list.to_string(), "[1, 2, 3]");
+	/// assert_eq!(List::<u8>::new().to_string(), "[]");
+	/// ```
+	fn fmt(self: &Self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let mut current: &Option<Box<Node<T>>> = &self.head;
+
+		write!(formatter, "[")?;
+		if let Some(node) = current {
+			write!(formatter, "{}", node.value)?;
+			current = &node.next;
+		}
+		while let Some(node) = current {
+			write!(formatter, ", {}", node.value)?;
+			current = &node.next;
+		}
+		write!(formatter, "]")
+	}
+}
+
+impl<T> Drop for List<T> {
+	/// Drops every node of the calling List instance one after the other,
+	/// instead of recursively, so that long lists do not overflow the stack.
+	fn drop(self: &mut Self) {
+		let mut current: Option<Box<Node<T>>> = self.head.take();
+
+		while let Some(mut node) = current {
+			current = node.next.take();
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -1100,6 +1484,405 @@ mod tests {
 	}
 	// endregion
 
+	// region: list_clear_03
+	#[test]
+	fn list_clear_03() {
+		let mut list: List<u8> = List::new();
+
+		for i in 0..1_000_000 {
+			list.push_front(i as u8);
+		}
+
+		list.clear();
+		assert_eq!(list, List { head: None });
+	}
+	// endregion
+
+	// region: list_max_00
+	#[test]
+	fn list_max_00() {
+		let list: List<u8> = List::new();
+
+		assert_eq!(list.max(), None);
+	}
+	// endregion
+
+	// region: list_max_01
+	#[test]
+	fn list_max_01() {
+		let list: List<u8> = [3, 1, 2].into_iter().collect();
+
+		assert_eq!(list.max(), Some(&3));
+	}
+	// endregion
+
+	// region: list_max_02
+	#[test]
+	fn list_max_02() {
+		let list: List<i8> = [-7, 29, 88, -14, 112, -53, -95].into_iter().collect();
+
+		assert_eq!(list.max(), Some(&112));
+	}
+	// endregion
+
+	// region: list_max_03
+	#[test]
+	fn list_max_03() {
+		let list: List<i8> = [-42].into_iter().collect();
+
+		assert_eq!(list.max(), Some(&-42));
+	}
+	// endregion
+
+	// region: list_min_00
+	#[test]
+	fn list_min_00() {
+		let list: List<u8> = List::new();
+
+		assert_eq!(list.min(), None);
+	}
+	// endregion
+
+	// region: list_min_01
+	#[test]
+	fn list_min_01() {
+		let list: List<u8> = [3, 1, 2].into_iter().collect();
+
+		assert_eq!(list.min(), Some(&1));
+	}
+	// endregion
+
+	// region: list_min_02
+	#[test]
+	fn list_min_02() {
+		let list: List<i8> = [-7, 29, 88, -14, 112, -53, -95].into_iter().collect();
+
+		assert_eq!(list.min(), Some(&-95));
+	}
+	// endregion
+
+	// region: list_min_03
+	#[test]
+	fn list_min_03() {
+		let list: List<i8> = [-42].into_iter().collect();
+
+		assert_eq!(list.min(), Some(&-42));
+	}
+	// endregion
+
+	// region: list_eq_unordered_00
+	#[test]
+	fn list_eq_unordered_00() {
+		let list0: List<u8> = List::new();
+		let list1: List<u8> = List::new();
+
+		assert_eq!(list0.eq_unordered(&list1), true);
+	}
+	// endregion
+
+	// region: list_eq_unordered_01
+	#[test]
+	fn list_eq_unordered_01() {
+		let list0: List<u8> = [1, 2, 3].into_iter().collect();
+		let list1: List<u8> = [3, 1, 2].into_iter().collect();
+
+		assert_eq!(list0.eq_unordered(&list1), true);
+		assert_eq!(list1.eq_unordered(&list0), true);
+	}
+	// endregion
+
+	// region: list_eq_unordered_02
+	#[test]
+	fn list_eq_unordered_02() {
+		let list0: List<u8> = [1, 2, 2].into_iter().collect();
+		let list1: List<u8> = [1, 2, 3].into_iter().collect();
+
+		assert_eq!(list0.eq_unordered(&list1), false);
+		assert_eq!(list1.eq_unordered(&list0), false);
+	}
+	// endregion
+
+	// region: list_eq_unordered_03
+	#[test]
+	fn list_eq_unordered_03() {
+		let list0: List<i8> = [-7, 29, 29, -14].into_iter().collect();
+		let list1: List<i8> = [29, -14, 29, -7].into_iter().collect();
+		let list2: List<i8> = [29, -14, -7].into_iter().collect();
+
+		assert_eq!(list0.eq_unordered(&list1), true);
+		assert_eq!(list0.eq_unordered(&list2), false);
+		assert_eq!(list2.eq_unordered(&list0), false);
+	}
+	// endregion
+
+	// region: list_rotate_left_00
+	#[test]
+	fn list_rotate_left_00() {
+		let mut list: List<u8> = List::new();
+
+		list.rotate_left(0);
+		assert_eq!(list, List { head: None });
+		list.rotate_left(3);
+		assert_eq!(list, List { head: None });
+	}
+	// endregion
+
+	// region: list_rotate_left_01
+	#[test]
+	fn list_rotate_left_01() {
+		let mut list: List<u8> = [1, 2, 3, 4].into_iter().collect();
+
+		list.rotate_left(1);
+		assert_eq!(list, [2, 3, 4, 1].into_iter().collect());
+	}
+	// endregion
+
+	// region: list_rotate_left_02
+	#[test]
+	fn list_rotate_left_02() {
+		let mut list: List<u8> = [1, 2, 3, 4].into_iter().collect();
+
+		list.rotate_left(0);
+		assert_eq!(list, [1, 2, 3, 4].into_iter().collect());
+		list.rotate_left(4);
+		assert_eq!(list, [1, 2, 3, 4].into_iter().collect());
+		list.rotate_left(8);
+		assert_eq!(list, [1, 2, 3, 4].into_iter().collect());
+	}
+	// endregion
+
+	// region: list_rotate_left_03
+	#[test]
+	fn list_rotate_left_03() {
+		let mut list: List<C> = [C::new(-7), C::new(29), C::new(88)].into_iter().collect();
+
+		list.rotate_left(5);
+		assert_eq!(list, [C::new(88), C::new(-7), C::new(29)].into_iter().collect());
+		list.rotate_left(2);
+		assert_eq!(list, [C::new(29), C::new(88), C::new(-7)].into_iter().collect());
+	}
+	// endregion
+
+	// region: list_rotate_left_04
+	#[test]
+	fn list_rotate_left_04() {
+		let mut list: List<B> = [B::new(0x42)].into_iter().collect();
+
+		list.rotate_left(1);
+		assert_eq!(list, [B::new(0x42)].into_iter().collect());
+		list.rotate_left(usize::MAX);
+		assert_eq!(list, [B::new(0x42)].into_iter().collect());
+	}
+	// endregion
+
+	// region: list_append_00
+	#[test]
+	fn list_append_00() {
+		let mut list0: List<u8> = List::new();
+		let mut list1: List<u8> = List::new();
+
+		list0.append(&mut list1);
+		assert_eq!(list0, List { head: None });
+		assert_eq!(list1, List { head: None });
+	}
+	// endregion
+
+	// region: list_append_01
+	#[test]
+	fn list_append_01() {
+		let mut list0: List<u8> = List::new();
+		let mut list1: List<u8> = [1, 2, 3].into_iter().collect();
+
+		list0.append(&mut list1);
+		assert_eq!(list0, [1, 2, 3].into_iter().collect());
+		assert_eq!(list1.count(), 0);
+	}
+	// endregion
+
+	// region: list_append_02
+	#[test]
+	fn list_append_02() {
+		let mut list0: List<u8> = [1, 2, 3].into_iter().collect();
+		let mut list1: List<u8> = List::new();
+
+		list0.append(&mut list1);
+		assert_eq!(list0, [1, 2, 3].into_iter().collect());
+		assert_eq!(list1.count(), 0);
+	}
+	// endregion
+
+	// region: list_append_03
+	#[test]
+	fn list_append_03() {
+		let mut list0: List<C> = [C::new(-7), C::new(29)].into_iter().collect();
+		let mut list1: List<C> = [C::new(88), C::new(-14), C::new(3)].into_iter().collect();
+
+		list0.append(&mut list1);
+		assert_eq!(
+			list0,
+			[C::new(-7), C::new(29), C::new(88), C::new(-14), C::new(3)].into_iter().collect()
+		);
+		assert_eq!(list1.count(), 0);
+	}
+	// endregion
+
+	// region: list_append_04
+	#[test]
+	fn list_append_04() {
+		let mut list0: List<B> = [B::new(0x42)].into_iter().collect();
+		let mut list1: List<B> = [B::new(0x21)].into_iter().collect();
+
+		list0.append(&mut list1);
+		list1.push_back(B::new(0x84));
+		list0.append(&mut list1);
+		assert_eq!(list0, [B::new(0x42), B::new(0x21), B::new(0x84)].into_iter().collect());
+		assert_eq!(list1.count(), 0);
+	}
+	// endregion
+
+	// region: list_split_off_00
+	#[test]
+	fn list_split_off_00() {
+		let mut list0: List<u8> = List::new();
+		let list1: List<u8> = list0.split_off(0);
+
+		assert_eq!(list0, List { head: None });
+		assert_eq!(list1, List { head: None });
+	}
+	// endregion
+
+	// region: list_split_off_01
+	#[test]
+	fn list_split_off_01() {
+		let mut list0: List<u8> = [1, 2, 3, 4, 5].into_iter().collect();
+		let list1: List<u8> = list0.split_off(2);
+
+		assert_eq!(list0, [1, 2].into_iter().collect());
+		assert_eq!(list1, [3, 4, 5].into_iter().collect());
+	}
+	// endregion
+
+	// region: list_split_off_02
+	#[test]
+	fn list_split_off_02() {
+		let mut list0: List<C> = [C::new(-7), C::new(29), C::new(88)].into_iter().collect();
+		let list1: List<C> = list0.split_off(0);
+
+		assert_eq!(list0, List { head: None });
+		assert_eq!(list1, [C::new(-7), C::new(29), C::new(88)].into_iter().collect());
+	}
+	// endregion
+
+	// region: list_split_off_03
+	#[test]
+	fn list_split_off_03() {
+		let mut list0: List<C> = [C::new(-7), C::new(29), C::new(88)].into_iter().collect();
+		let list1: List<C> = list0.split_off(3);
+
+		assert_eq!(list0, [C::new(-7), C::new(29), C::new(88)].into_iter().collect());
+		assert_eq!(list1, List { head: None });
+	}
+	// endregion
+
+	// region: list_split_off_04
+	#[test]
+	#[should_panic(expected = "tried to split at out of bound index 4 (count is 3)")]
+	fn list_split_off_04() {
+		let mut list: List<B> = [B::new(0x01), B::new(0x02), B::new(0x03)].into_iter().collect();
+
+		list.split_off(4);
+	}
+	// endregion
+
+	// region: list_split_off_05
+	#[test]
+	#[should_panic(expected = "tried to split at out of bound index 1 (count is 0)")]
+	fn list_split_off_05() {
+		let mut list: List<B> = List::new();
+
+		list.split_off(1);
+	}
+	// endregion
+
+	// region: list_split_off_06
+	#[test]
+	fn list_split_off_06() {
+		let mut list0: List<B> = [B::new(0x01), B::new(0x02), B::new(0x03)].into_iter().collect();
+		let mut list1: List<B> = list0.split_off(1);
+
+		list0.append(&mut list1);
+		assert_eq!(list0, [B::new(0x01), B::new(0x02), B::new(0x03)].into_iter().collect());
+	}
+	// endregion
+
+	// region: list_contains_00
+	#[test]
+	fn list_contains_00() {
+		let list: List<i8> = List::new();
+
+		assert_eq!(list.contains(&0), false);
+	}
+	// endregion
+
+	// region: list_contains_01
+	#[test]
+	fn list_contains_01() {
+		let list: List<i8> = [-7, 29, 88, 29].into_iter().collect();
+
+		assert_eq!(list.contains(&-7), true);
+		assert_eq!(list.contains(&29), true);
+		assert_eq!(list.contains(&88), true);
+	}
+	// endregion
+
+	// region: list_contains_02
+	#[test]
+	fn list_contains_02() {
+		let list: List<i8> = [-7, 29, 88, 29].into_iter().collect();
+
+		assert_eq!(list.contains(&7), false);
+		assert_eq!(list.contains(&i8::MIN), false);
+	}
+	// endregion
+
+	// region: list_position_00
+	#[test]
+	fn list_position_00() {
+		let list: List<i8> = List::new();
+
+		assert_eq!(list.position(&0), None);
+	}
+	// endregion
+
+	// region: list_position_01
+	#[test]
+	fn list_position_01() {
+		let list: List<i8> = [-7, 29, 88, 29].into_iter().collect();
+
+		assert_eq!(list.position(&-7), Some(0));
+		assert_eq!(list.position(&88), Some(2));
+	}
+	// endregion
+
+	// region: list_position_02
+	#[test]
+	fn list_position_02() {
+		let list: List<i8> = [-7, 29, 88, 29].into_iter().collect();
+
+		assert_eq!(list.position(&29), Some(1));
+	}
+	// endregion
+
+	// region: list_position_03
+	#[test]
+	fn list_position_03() {
+		let list: List<i8> = [-7, 29, 88, 29].into_iter().collect();
+
+		assert_eq!(list.position(&7), None);
+		assert_eq!(list.position(&i8::MAX), None);
+	}
+	// endregion
+
 	// region: list_operator_index_00
 	#[test]
 	fn list_operator_index_00() {
@@ -1563,4 +2346,156 @@ mod tests {
 		assert_eq!(list, List { head: None });
 	}
 	// endregion
+
+	// region: list_from_iter_00
+	#[test]
+	fn list_from_iter_00() {
+		let list: List<A> = List::from_iter([]);
+
+		assert_eq!(list, List { head: None });
+	}
+	// endregion
+
+	// region: list_from_iter_01
+	#[test]
+	fn list_from_iter_01() {
+		let list: List<B> = List::from_iter([B::new(0x01), B::new(0x02), B::new(0x03)]);
+		let mut expected: List<B> = List::new();
+
+		expected.push_back(B::new(0x01));
+		expected.push_back(B::new(0x02));
+		expected.push_back(B::new(0x03));
+
+		assert_eq!(list, expected);
+		assert_eq!(list[0], expected[0]);
+		assert_eq!(list[1], expected[1]);
+		assert_eq!(list[2], expected[2]);
+	}
+	// endregion
+
+	// region: list_from_iter_02
+	#[test]
+	fn list_from_iter_02() {
+		let list: List<C> = (-2..3).map(C::new).collect();
+
+		assert_eq!(list.count(), 5);
+		assert_eq!(list[0], C::new(-2));
+		assert_eq!(list[4], C::new(2));
+	}
+	// endregion
+
+	// region: list_extend_00
+	#[test]
+	fn list_extend_00() {
+		let mut list: List<A> = List { head: None };
+
+		list.extend([]);
+
+		assert_eq!(list, List { head: None });
+	}
+	// endregion
+
+	// region: list_extend_01
+	#[test]
+	fn list_extend_01() {
+		let mut list: List<B> = List {
+			head: Some(Box::new(Node {
+				value: B::new(0x10),
+				next: Some(Box::new(Node { value: B::new(0x20), next: None })),
+			})),
+		};
+
+		list.extend([B::new(0x30), B::new(0x40)]);
+
+		assert_eq!(
+			list,
+			List {
+				head: Some(Box::new(Node {
+					value: B::new(0x10),
+					next: Some(Box::new(Node {
+						value: B::new(0x20),
+						next: Some(Box::new(Node {
+							value: B::new(0x30),
+							next: Some(Box::new(Node { value: B::new(0x40), next: None })),
+						})),
+					})),
+				}))
+			}
+		);
+	}
+	// endregion
+
+	// region: list_extend_02
+	#[test]
+	fn list_extend_02() {
+		let mut list: List<C> = List { head: None };
+
+		list.extend([C::new(-7)]);
+		list.extend(Vec::new());
+		list.extend([C::new(8), C::new(-9)]);
+
+		assert_eq!(list.count(), 3);
+		assert_eq!(list[0], C::new(-7));
+		assert_eq!(list[1], C::new(8));
+		assert_eq!(list[2], C::new(-9));
+	}
+	// endregion
+
+	// region: list_display_00
+	#[test]
+	fn list_display_00() {
+		let list: List<u8> = List::new();
+
+		assert_eq!(list.to_string(), "[]");
+	}
+	// endregion
+
+	// region: list_display_01
+	#[test]
+	fn list_display_01() {
+		let list: List<u8> = [42].into_iter().collect();
+
+		assert_eq!(list.to_string(), "[42]");
+	}
+	// endregion
+
+	// region: list_display_02
+	#[test]
+	fn list_display_02() {
+		let list: List<u8> = [0, 127, 255].into_iter().collect();
+
+		assert_eq!(list.to_string(), "[0, 127, 255]");
+	}
+	// endregion
+
+	// region: list_display_03
+	#[test]
+	fn list_display_03() {
+		let list: List<&str> = ["Hello", "World!"].into_iter().collect();
+
+		assert_eq!(format!("{list}"), "[Hello, World!]");
+	}
+	// endregion
+
+	// region: list_drop_00
+	#[test]
+	fn list_drop_00() {
+		let list: List<A> = List { head: None };
+
+		drop(list);
+	}
+	// endregion
+
+	// region: list_drop_01
+	#[test]
+	fn list_drop_01() {
+		let mut list: List<u8> = List::new();
+
+		for i in 0..1_000_000 {
+			list.push_front(i as u8);
+		}
+
+		drop(list);
+	}
+	// endregion
 }
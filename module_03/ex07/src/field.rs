@@ -15,11 +15,12 @@ pub trait Field: Sized {
 	///
 	/// ### Parameters
 	/// * `target` - The string to append the encoded field to.
+	/// * `delimiter` - The character separating the fields of the record.
 	///
 	/// ### Return
 	/// * `Ok(())` - The field was successfully encoded.
 	/// * `Err(EncodingError)` - The field could not be encoded.
-	fn encode(self: &Self, target: &mut String) -> Result<(), EncodingError>;
+	fn encode(self: &Self, target: &mut String, delimiter: char) -> Result<(), EncodingError>;
 }
 
 impl Field for String {
@@ -27,12 +28,16 @@ impl Field for String {
 		Ok(field.to_string())
 	}
 
-	fn encode(self: &Self, target: &mut String) -> Result<(), EncodingError> {
-		if self.contains([',', '\n']) {
-			Err(EncodingError)
+	fn encode(self: &Self, target: &mut String, delimiter: char) -> Result<(), EncodingError> {
+		if self.contains([delimiter, '"', '\n', '\r']) {
+			target.push('"');
+			target.push_str(&self.replace('"', "\"\""));
+			target.push('"');
 		} else {
-			Ok(target.push_str(self))
+			target.push_str(self);
 		}
+
+		Ok(())
 	}
 }
 
@@ -49,14 +54,45 @@ where
 			Err(err) => Err(err),
 		}
 	}
-	fn encode(self: &Self, target: &mut String) -> Result<(), EncodingError> {
+	fn encode(self: &Self, target: &mut String, delimiter: char) -> Result<(), EncodingError> {
 		match self {
-			Some(value) => value.encode(target),
+			Some(value) => value.encode(target, delimiter),
 			None => Ok(()),
 		}
 	}
 }
 
+impl Field for bool {
+	fn decode(field: &str) -> Result<Self, DecodingError> {
+		match field {
+			"true" => Ok(true),
+			"false" => Ok(false),
+			_ => Err(DecodingError),
+		}
+	}
+
+	fn encode(self: &Self, target: &mut String, _delimiter: char) -> Result<(), EncodingError> {
+		target.push_str(if *self { "true" } else { "false" });
+
+		Ok(())
+	}
+}
+
+impl Field for char {
+	fn decode(field: &str) -> Result<Self, DecodingError> {
+		let mut chars: std::str::Chars<'_> = field.chars();
+
+		match (chars.next(), chars.next()) {
+			(Some(c), None) => Ok(c),
+			_ => Err(DecodingError),
+		}
+	}
+
+	fn encode(self: &Self, target: &mut String, delimiter: char) -> Result<(), EncodingError> {
+		self.to_string().encode(target, delimiter)
+	}
+}
+
 macro_rules! impl_field_for_int {
 	($($type:ty)*) => {
 		$(
@@ -68,7 +104,11 @@ macro_rules! impl_field_for_int {
 					}
 				}
 
-				fn encode(self: &Self, target: &mut String) -> Result<(), EncodingError> {
+				fn encode(
+					self: &Self,
+					target: &mut String,
+					_delimiter: char,
+				) -> Result<(), EncodingError> {
 					use std::fmt::Write;
 
 					match write!(target, "{}", self) {
@@ -82,3 +122,37 @@ macro_rules! impl_field_for_int {
 }
 
 impl_field_for_int!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+/// Floating-point fields only accept finite values:
+/// decoding `NaN` or an infinity fails with `DecodingError`,
+/// and encoding them fails with `EncodingError`,
+/// so that every encoded record can be decoded back.
+macro_rules! impl_field_for_float {
+	($($type:ty)*) => {
+		$(
+			impl Field for $type {
+				fn decode(field: &str) -> Result<Self, DecodingError> {
+					match field.parse::<$type>() {
+						Ok(value) if value.is_finite() => Ok(value),
+						_ => Err(DecodingError),
+					}
+				}
+
+				fn encode(
+					self: &Self,
+					target: &mut String,
+					_delimiter: char,
+				) -> Result<(), EncodingError> {
+					if !self.is_finite() {
+						return Err(EncodingError);
+					}
+					target.push_str(&self.to_string());
+
+					Ok(())
+				}
+			}
+		)*
+	};
+}
+
+impl_field_for_float!(f32 f64);
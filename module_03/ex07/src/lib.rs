@@ -3,7 +3,56 @@ mod field;
 mod record;
 
 use crate::error::{DecodingError, EncodingError};
-use crate::record::Record;
+use crate::record::{split_fields, Record};
+
+/// Splits a CSV content into its records.
+/// Records are separated by newlines, except for the ones within a quoted field.
+/// As in `split_fields`, a field is quoted only if it starts with a quote,
+/// any other quote being part of the field.
+///
+/// ### Parameters
+/// * `content` - The CSV content to split.
+/// * `delimiter` - The character separating the fields of each record.
+///
+/// ### Return
+/// The records of `content`, in order,
+/// each along with the 0-based index of the line it starts on.
+fn split_records(content: &str, delimiter: char) -> Vec<(usize, &str)> {
+	let mut records: Vec<(usize, &str)> = Vec::new();
+	let mut chars: std::iter::Peekable<std::str::CharIndices<'_>> =
+		content.char_indices().peekable();
+	let mut start: usize = 0;
+	let mut line_index: usize = 0;
+	let mut record_line_index: usize = 0;
+	let mut is_field_start: bool = true;
+	let mut is_quoted: bool = false;
+
+	while let Some((i, c)) = chars.next() {
+		if c == '\n' {
+			line_index += 1;
+		}
+		if is_quoted {
+			if c == '"' && chars.next_if(|&(_, next): &(usize, char)| next == '"').is_none() {
+				is_quoted = false;
+			}
+		} else if c == '"' && is_field_start {
+			is_quoted = true;
+		} else if c == '\n' {
+			records.push((record_line_index, &content[start..i]));
+			start = i + 1;
+			record_line_index = line_index;
+		}
+		is_field_start = !is_quoted && (c == delimiter || c == '\n');
+	}
+	if start < content.len() {
+		records.push((record_line_index, &content[start..]));
+	}
+
+	records
+		.into_iter()
+		.map(|(i, record): (usize, &str)| (i, record.strip_suffix('\r').unwrap_or(record)))
+		.collect()
+}
 
 /// Decodes a CSV content from its `str` representation to a collection of records.
 ///
@@ -16,11 +65,68 @@ use crate::record::Record;
 /// ### Return
 /// * `Ok(Vec<R>)` - The decoded records.
 /// * `Err(DecodingError)` - The CSV content could not be decoded.
+#[inline(always)]
 pub fn decode_csv<R: Record>(content: &str) -> Result<Vec<R>, DecodingError> {
+	decode_csv_with_delimiter(content, ',')
+}
+
+/// Decodes a CSV content whose fields are separated by a custom delimiter
+/// from its `str` representation to a collection of records.
+///
+/// ### Type parameters
+/// * `R` - The type of the record to decode.
+///
+/// ### Parameters
+/// * `content` - The CSV content to decode.
+/// * `delimiter` - The character separating the fields of each record.
+///
+/// ### Return
+/// * `Ok(Vec<R>)` - The decoded records.
+/// * `Err(DecodingError)` - The CSV content could not be decoded.
+pub fn decode_csv_with_delimiter<R: Record>(
+	content: &str,
+	delimiter: char,
+) -> Result<Vec<R>, DecodingError> {
+	let mut records: Vec<R> = Vec::new();
+
+	for (_, record) in split_records(content, delimiter) {
+		match R::decode_with_delimiter(record, delimiter) {
+			Ok(record) => records.push(record),
+			Err(err) => return Err(err),
+		}
+	}
+
+	Ok(records)
+}
+
+/// Decodes a CSV content from its `str` representation to a collection of records,
+/// where the first line names the columns, so that they are mapped to the fields of the records by name
+/// rather than by position.
+///
+/// ### Type parameters
+/// * `R` - The type of the record to decode.
+///
+/// ### Parameters
+/// * `content` - The CSV content to decode.
+///
+/// ### Return
+/// * `Ok(Vec<R>)` - The decoded records.
+/// * `Err(DecodingError)` - The CSV content could not be decoded, has no header line,
+///   or a column required by `R` is missing.
+pub fn decode_csv_with_header<R: Record>(content: &str) -> Result<Vec<R>, DecodingError> {
+	let mut lines: std::vec::IntoIter<(usize, &str)> = split_records(content, ',').into_iter();
+	let headers: Vec<String> = match lines.next() {
+		Some((_, line)) => split_fields(line, ',')?,
+		None => return Err(DecodingError),
+	};
+	let headers: Vec<&str> = headers.iter().map(String::as_str).collect();
 	let mut records: Vec<R> = Vec::new();
 
-	for line in content.lines() {
-		match R::decode(line) {
+	for (_, line) in lines {
+		let fields: Vec<String> = split_fields(line, ',')?;
+		let fields: Vec<&str> = fields.iter().map(String::as_str).collect();
+
+		match R::decode_by_header(&headers, &fields) {
 			Ok(record) => records.push(record),
 			Err(err) => return Err(err),
 		}
@@ -29,6 +135,34 @@ pub fn decode_csv<R: Record>(content: &str) -> Result<Vec<R>, DecodingError> {
 	Ok(records)
 }
 
+/// Decodes a CSV content from its `str` representation to a collection of records,
+/// skipping the lines that could not be decoded instead of aborting.
+///
+/// ### Type parameters
+/// * `R` - The type of the record to decode.
+///
+/// ### Parameters
+/// * `content` - The CSV content to decode.
+///
+/// ### Return
+/// A tuple containing:
+/// * The records decoded from the valid lines, in order.
+/// * The 0-based index of each line that could not be decoded, along with its error, in order.
+///   For a record spanning several lines, the index of the line it starts on is reported.
+pub fn decode_csv_lossy<R: Record>(content: &str) -> (Vec<R>, Vec<(usize, DecodingError)>) {
+	let mut records: Vec<R> = Vec::new();
+	let mut errors: Vec<(usize, DecodingError)> = Vec::new();
+
+	for (i, record) in split_records(content, ',') {
+		match R::decode(record) {
+			Ok(record) => records.push(record),
+			Err(err) => errors.push((i, err)),
+		}
+	}
+
+	(records, errors)
+}
+
 /// Encodes a CSV content from a collection of records to its `str` representation.
 ///
 /// ### Type parameters
@@ -40,11 +174,32 @@ pub fn decode_csv<R: Record>(content: &str) -> Result<Vec<R>, DecodingError> {
 /// ### Return
 /// * `Ok(String)` - The encoded records.
 /// * `Err(EncodingError)` - The CSV content could not be encoded.
+#[inline(always)]
 pub fn encode_csv<R: Record>(records: &[R]) -> Result<String, EncodingError> {
+	encode_csv_with_delimiter(records, ',')
+}
+
+/// Encodes a CSV content from a collection of records to its `str` representation,
+/// separating the fields of each record by a custom delimiter.
+///
+/// ### Type parameters
+/// * `R` - The type of the record to encode.
+///
+/// ### Parameters
+/// * `records` - The records to encode.
+/// * `delimiter` - The character separating the fields of each record.
+///
+/// ### Return
+/// * `Ok(String)` - The encoded records.
+/// * `Err(EncodingError)` - The CSV content could not be encoded.
+pub fn encode_csv_with_delimiter<R: Record>(
+	records: &[R],
+	delimiter: char,
+) -> Result<String, EncodingError> {
 	let mut content: String = String::new();
 
 	for record in records {
-		match record.encode(&mut content) {
+		match record.encode_with_delimiter(&mut content, delimiter) {
 			Ok(_) => content.push('\n'),
 			Err(err) => return Err(err),
 		}
@@ -53,6 +208,93 @@ pub fn encode_csv<R: Record>(records: &[R]) -> Result<String, EncodingError> {
 	Ok(content)
 }
 
+/// Encodes a collection of records to a human-readable table,
+/// where every column is padded to the width of its widest value, and columns are separated by `" | "`.
+/// Note that the resulting table is not valid CSV, and is meant to be used for debugging purposes.
+///
+/// ### Type parameters
+/// * `R` - The type of the record to encode.
+///
+/// ### Parameters
+/// * `records` - The records to encode.
+///
+/// ### Return
+/// * `Ok(String)` - The encoded table.
+/// * `Err(EncodingError)` - The records could not be encoded.
+pub fn to_aligned_table<R: Record>(records: &[R]) -> Result<String, EncodingError> {
+	let mut rows: Vec<Vec<String>> = Vec::with_capacity(records.len());
+	let mut widths: Vec<usize> = Vec::new();
+
+	for record in records {
+		let mut line: String = String::new();
+
+		record.encode(&mut line)?;
+
+		let fields: Vec<String> = match split_fields(&line, ',') {
+			Ok(fields) => fields,
+			Err(DecodingError) => return Err(EncodingError),
+		};
+
+		for (i, field) in fields.iter().enumerate() {
+			let width: usize = field.chars().count();
+
+			match widths.get_mut(i) {
+				Some(max_width) => *max_width = (*max_width).max(width),
+				None => widths.push(width),
+			}
+		}
+		rows.push(fields);
+	}
+
+	let mut table: String = String::new();
+
+	for fields in rows {
+		for (i, field) in fields.iter().enumerate() {
+			if i + 1 < fields.len() {
+				table.push_str(&format!("{:<width$} | ", field, width = widths[i]));
+			} else {
+				table.push_str(field);
+			}
+		}
+		table.push('\n');
+	}
+
+	Ok(table)
+}
+
+/// Checks whether a CSV field looks like a number.
+///
+/// ### Parameters
+/// * `field` - The field to check.
+///
+/// ### Return
+/// * `true` - The field looks like a number.
+/// * `false` - The field does not look like a number.
+fn is_numeric(field: &str) -> bool {
+	field.bytes().any(|c: u8| c.is_ascii_digit()) && field.parse::<f64>().is_ok()
+}
+
+/// Guesses whether the first line of a CSV content is a header.
+/// This is a best-effort heuristic: the first line is considered to be a header
+/// if none of its fields looks like a number, while at least one field of a subsequent line does.
+///
+/// ### Parameters
+/// * `content` - The CSV content to check.
+///
+/// ### Return
+/// * `true` - The first line of `content` looks like a header.
+/// * `false` - The first line of `content` does not look like a header.
+pub fn looks_like_header(content: &str) -> bool {
+	let mut lines: std::str::Lines<'_> = content.lines();
+
+	match lines.next() {
+		Some(first_line) if !first_line.split(',').any(is_numeric) => (),
+		_ => return false,
+	}
+
+	lines.any(|line: &str| line.split(',').any(is_numeric))
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -111,6 +353,36 @@ mod tests {
 	});
 	// endregion
 
+	// region: Struct D
+	#[derive(Debug, Eq, PartialEq)]
+	struct D {
+		a: String,
+		b: String,
+	}
+
+	impl_record_for_struct!(D { a: String, b: String });
+	// endregion
+
+	// region: Struct E
+	#[derive(Debug, Eq, PartialEq)]
+	struct E {
+		a: bool,
+		b: char,
+	}
+
+	impl_record_for_struct!(E { a: bool, b: char });
+	// endregion
+
+	// region: Struct F
+	#[derive(Debug, PartialEq)]
+	struct F {
+		a: f32,
+		b: f64,
+	}
+
+	impl_record_for_struct!(F { a: f32, b: f64 });
+	// endregion
+
 	// region: decode_csv_00
 	#[test]
 	fn decode_csv_00() {
@@ -191,81 +463,835 @@ mod tests {
 	}
 	// endregion
 
-	// region: encode_csv_00
+	// region: decode_csv_06
 	#[test]
-	fn encode_csv_00() {
-		let records: Vec<A> = vec![];
-		let content: String = match encode_csv(&records) {
+	fn decode_csv_06() {
+		let content: &str = "\"a,b\",c\n";
+		let records: Vec<D> = match decode_csv(content) {
 			Ok(value) => value,
-			Err(EncodingError) => panic!("could not encode CSV"),
+			Err(DecodingError) => panic!("could not decode CSV"),
 		};
 
-		assert_eq!(content, "");
+		assert_eq!(records, vec![D { a: "a,b".to_string(), b: "c".to_string() }]);
 	}
 	// endregion
 
-	// region: encode_csv_01
+	// region: decode_csv_07
 	#[test]
-	fn encode_csv_01() {
-		let records: Vec<B> = vec![B { a: "Never gonna give you up".to_string(), b: Some(98) }];
-		let content: String = match encode_csv(&records) {
+	fn decode_csv_07() {
+		let content: &str = "\
+			\"Hello, \"\"World\"\"!\",\"\"\n\
+			\"multi\nline\",\"\"\"\"\n\
+			\"\",plain\n\
+		";
+		let records: Vec<D> = match decode_csv(content) {
 			Ok(value) => value,
-			Err(EncodingError) => panic!("could not encode CSV"),
+			Err(DecodingError) => panic!("could not decode CSV"),
 		};
 
-		assert_eq!(content, "Never gonna give you up,98\n");
+		assert_eq!(
+			records,
+			vec![
+				D { a: "Hello, \"World\"!".to_string(), b: "".to_string() },
+				D { a: "multi\nline".to_string(), b: "\"".to_string() },
+				D { a: "".to_string(), b: "plain".to_string() },
+			]
+		);
 	}
 	// endregion
 
-	// region: encode_csv_02
+	// region: decode_csv_08
 	#[test]
-	fn encode_csv_02() {
-		let records: Vec<C> = vec![
-			// region: records[0]
-			C { a: 66, b: 65, c: 63, d: 60, e: 56, f: 51, g: 45, h: 38, i: 30, j: 21, k: 11, l: 0 },
-			// endregion
-			// region: records[1]
-			C { a: 12, b: 10, c: 8, d: 6, e: 4, f: 2, g: 1, h: 3, i: 5, j: 7, k: 9, l: 11 },
-			// endregion
-			// region: records[2]
-			C {
-				a: 123,
-				b: 234,
-				c: 345,
-				d: 456,
-				e: 567,
-				f: 678,
-				g: -12,
-				h: -23,
-				i: -34,
-				j: -45,
-				k: -56,
-				l: -67,
-			},
-			// endregion
-		];
-		let content: String = match encode_csv(&records) {
+	fn decode_csv_08() {
+		assert_eq!(decode_csv::<D>("\"a,b,c\n"), Err(DecodingError));
+		assert_eq!(decode_csv::<D>("\"a\"b,c\n"), Err(DecodingError));
+		assert_eq!(decode_csv::<D>("\"a,b\",c,d\n"), Err(DecodingError));
+	}
+	// endregion
+
+	// region: decode_csv_09
+	#[test]
+	fn decode_csv_09() {
+		let content: &str = "ab\"c,1\nde,2\nfg,3\n";
+		let records: Vec<B> = match decode_csv(content) {
 			Ok(value) => value,
-			Err(EncodingError) => panic!("could not encode CSV"),
+			Err(DecodingError) => panic!("could not decode CSV"),
 		};
 
 		assert_eq!(
-			content,
-			"\
-			66,65,63,60,56,51,45,38,30,21,11,0\n\
-			12,10,8,6,4,2,1,3,5,7,9,11\n\
-			123,234,345,456,567,678,-12,-23,-34,-45,-56,-67\n"
+			records,
+			vec![
+				B { a: "ab\"c".to_string(), b: Some(1) },
+				B { a: "de".to_string(), b: Some(2) },
+				B { a: "fg".to_string(), b: Some(3) },
+			]
 		);
 	}
 	// endregion
 
-	// region: encode_csv_03
+	// region: decode_csv_lossy_00
 	#[test]
-	fn encode_csv_03() {
-		let records: Vec<B> =
-			vec![B { a: "May I have your attention, please?".to_string(), b: None }];
+	fn decode_csv_lossy_00() {
+		let content: &str = "";
+		let (records, errors): (Vec<A>, Vec<(usize, DecodingError)>) = decode_csv_lossy(content);
+
+		assert_eq!(records, vec![]);
+		assert_eq!(errors, vec![]);
+	}
+	// endregion
+
+	// region: decode_csv_lossy_01
+	#[test]
+	fn decode_csv_lossy_01() {
+		let content: &str = "\
+			Hello,\n\
+			pouic\n\
+			,0\n\
+			World!,256\n\
+			World!,42\n\
+			a,b,c\n\
+		";
+		let (records, errors): (Vec<B>, Vec<(usize, DecodingError)>) = decode_csv_lossy(content);
+
+		assert_eq!(
+			records,
+			vec![
+				B { a: "Hello".to_string(), b: None },
+				B { a: "".to_string(), b: Some(0) },
+				B { a: "World!".to_string(), b: Some(42) },
+			]
+		);
+		assert_eq!(errors, vec![(1, DecodingError), (3, DecodingError), (5, DecodingError)]);
+	}
+	// endregion
+
+	// region: decode_csv_lossy_02
+	#[test]
+	fn decode_csv_lossy_02() {
+		let content: &str = "koala\n\nkoala\n";
+		let (records, errors): (Vec<A>, Vec<(usize, DecodingError)>) = decode_csv_lossy(content);
+
+		assert_eq!(records, vec![A {}]);
+		assert_eq!(errors, vec![(0, DecodingError), (2, DecodingError)]);
+	}
+	// endregion
+
+	// region: decode_csv_lossy_03
+	#[test]
+	fn decode_csv_lossy_03() {
+		let content: &str = "0,1,2,3,4,5,6,7,8,9,10,11\n";
+		let (records, errors): (Vec<C>, Vec<(usize, DecodingError)>) = decode_csv_lossy(content);
 
-		assert_eq!(encode_csv(&records), Err(EncodingError));
+		assert_eq!(
+			records,
+			vec![C { a: 0, b: 1, c: 2, d: 3, e: 4, f: 5, g: 6, h: 7, i: 8, j: 9, k: 10, l: 11 }]
+		);
+		assert_eq!(errors, vec![]);
+	}
+	// endregion
+
+	// region: decode_csv_lossy_04
+	#[test]
+	fn decode_csv_lossy_04() {
+		let content: &str = "\
+			Hello,World\n\
+			\"multi\nline\",a,b\n\
+			\"multi\nline\",c\n\
+			pouic\n\
+		";
+		let (records, errors): (Vec<D>, Vec<(usize, DecodingError)>) = decode_csv_lossy(content);
+
+		assert_eq!(
+			records,
+			vec![
+				D { a: "Hello".to_string(), b: "World".to_string() },
+				D { a: "multi\nline".to_string(), b: "c".to_string() },
+			]
+		);
+		assert_eq!(errors, vec![(1, DecodingError), (5, DecodingError)]);
+	}
+	// endregion
+
+	// region: decode_csv_lossy_05
+	#[test]
+	fn decode_csv_lossy_05() {
+		let content: &str = "\
+			ab\"c,1\n\
+			\"multi\nline\",x\n\
+			fg,3\n\
+		";
+		let (records, errors): (Vec<B>, Vec<(usize, DecodingError)>) = decode_csv_lossy(content);
+
+		assert_eq!(
+			records,
+			vec![B { a: "ab\"c".to_string(), b: Some(1) }, B { a: "fg".to_string(), b: Some(3) }]
+		);
+		assert_eq!(errors, vec![(1, DecodingError)]);
+	}
+	// endregion
+
+	// region: decode_csv_with_header_00
+	#[test]
+	fn decode_csv_with_header_00() {
+		let content: &str = "\
+			b,a\n\
+			,Hello\n\
+			0,\n\
+			42,World!\n\
+		";
+		let records: Vec<B> = match decode_csv_with_header(content) {
+			Ok(value) => value,
+			Err(DecodingError) => panic!("could not decode CSV"),
+		};
+
+		assert_eq!(
+			records,
+			vec![
+				B { a: "Hello".to_string(), b: None },
+				B { a: "".to_string(), b: Some(0) },
+				B { a: "World!".to_string(), b: Some(42) },
+			]
+		);
+	}
+	// endregion
+
+	// region: decode_csv_with_header_01
+	#[test]
+	fn decode_csv_with_header_01() {
+		let content: &str = "\
+			l,k,j,i,h,g,f,e,d,c,b,a\n\
+			11,10,9,8,7,6,5,4,3,2,1,0\n\
+		";
+		let records: Vec<C> = match decode_csv_with_header(content) {
+			Ok(value) => value,
+			Err(DecodingError) => panic!("could not decode CSV"),
+		};
+
+		assert_eq!(
+			records,
+			vec![C { a: 0, b: 1, c: 2, d: 3, e: 4, f: 5, g: 6, h: 7, i: 8, j: 9, k: 10, l: 11 }]
+		);
+	}
+	// endregion
+
+	// region: decode_csv_with_header_02
+	#[test]
+	fn decode_csv_with_header_02() {
+		let content: &str = "\
+			comment,b,\"a\"\n\
+			ignored,\"a,b\",c\n\
+		";
+		let records: Vec<D> = match decode_csv_with_header(content) {
+			Ok(value) => value,
+			Err(DecodingError) => panic!("could not decode CSV"),
+		};
+
+		assert_eq!(records, vec![D { a: "c".to_string(), b: "a,b".to_string() }]);
+	}
+	// endregion
+
+	// region: decode_csv_with_header_03
+	#[test]
+	fn decode_csv_with_header_03() {
+		let content: &str = "b\n42\n";
+
+		assert_eq!(decode_csv_with_header::<B>(content), Err(DecodingError));
+	}
+	// endregion
+
+	// region: decode_csv_with_header_04
+	#[test]
+	fn decode_csv_with_header_04() {
+		assert_eq!(decode_csv_with_header::<B>(""), Err(DecodingError));
+		assert_eq!(decode_csv_with_header::<B>("a,b\nHello\n"), Err(DecodingError));
+		assert_eq!(decode_csv_with_header::<B>("a,b\nHello,World!\n"), Err(DecodingError));
+	}
+	// endregion
+
+	// region: decode_csv_with_header_05
+	#[test]
+	fn decode_csv_with_header_05() {
+		let records: Vec<B> = match decode_csv_with_header("a,b\n") {
+			Ok(value) => value,
+			Err(DecodingError) => panic!("could not decode CSV"),
+		};
+
+		assert_eq!(records, vec![]);
+	}
+	// endregion
+
+	// region: encode_csv_00
+	#[test]
+	fn encode_csv_00() {
+		let records: Vec<A> = vec![];
+		let content: String = match encode_csv(&records) {
+			Ok(value) => value,
+			Err(EncodingError) => panic!("could not encode CSV"),
+		};
+
+		assert_eq!(content, "");
+	}
+	// endregion
+
+	// region: encode_csv_01
+	#[test]
+	fn encode_csv_01() {
+		let records: Vec<B> = vec![B { a: "Never gonna give you up".to_string(), b: Some(98) }];
+		let content: String = match encode_csv(&records) {
+			Ok(value) => value,
+			Err(EncodingError) => panic!("could not encode CSV"),
+		};
+
+		assert_eq!(content, "Never gonna give you up,98\n");
+	}
+	// endregion
+
+	// region: encode_csv_02
+	#[test]
+	fn encode_csv_02() {
+		let records: Vec<C> = vec![
+			// region: records[0]
+			C { a: 66, b: 65, c: 63, d: 60, e: 56, f: 51, g: 45, h: 38, i: 30, j: 21, k: 11, l: 0 },
+			// endregion
+			// region: records[1]
+			C { a: 12, b: 10, c: 8, d: 6, e: 4, f: 2, g: 1, h: 3, i: 5, j: 7, k: 9, l: 11 },
+			// endregion
+			// region: records[2]
+			C {
+				a: 123,
+				b: 234,
+				c: 345,
+				d: 456,
+				e: 567,
+				f: 678,
+				g: -12,
+				h: -23,
+				i: -34,
+				j: -45,
+				k: -56,
+				l: -67,
+			},
+			// endregion
+		];
+		let content: String = match encode_csv(&records) {
+			Ok(value) => value,
+			Err(EncodingError) => panic!("could not encode CSV"),
+		};
+
+		assert_eq!(
+			content,
+			"\
+			66,65,63,60,56,51,45,38,30,21,11,0\n\
+			12,10,8,6,4,2,1,3,5,7,9,11\n\
+			123,234,345,456,567,678,-12,-23,-34,-45,-56,-67\n"
+		);
+	}
+	// endregion
+
+	// region: encode_csv_03
+	#[test]
+	fn encode_csv_03() {
+		let records: Vec<B> =
+			vec![B { a: "May I have your attention, please?".to_string(), b: None }];
+		let content: String = match encode_csv(&records) {
+			Ok(value) => value,
+			Err(EncodingError) => panic!("could not encode CSV"),
+		};
+
+		assert_eq!(content, "\"May I have your attention, please?\",\n");
+	}
+	// endregion
+
+	// region: encode_csv_04
+	#[test]
+	fn encode_csv_04() {
+		let records: Vec<D> = vec![
+			D { a: "a,b".to_string(), b: "c".to_string() },
+			D { a: "Hello, \"World\"!".to_string(), b: "multi\nline".to_string() },
+			D { a: "\"".to_string(), b: "\r".to_string() },
+		];
+		let content: String = match encode_csv(&records) {
+			Ok(value) => value,
+			Err(EncodingError) => panic!("could not encode CSV"),
+		};
+
+		assert_eq!(
+			content,
+			"\
+			\"a,b\",c\n\
+			\"Hello, \"\"World\"\"!\",\"multi\nline\"\n\
+			\"\"\"\",\"\r\"\n"
+		);
+	}
+	// endregion
+
+	// region: encode_csv_05
+	#[test]
+	fn encode_csv_05() {
+		let records: Vec<D> = vec![
+			D { a: "a,b".to_string(), b: "c".to_string() },
+			D { a: "Hello, \"World\"!".to_string(), b: "multi\nline".to_string() },
+			D { a: "\"".to_string(), b: "\r".to_string() },
+			D { a: "".to_string(), b: "\r\n".to_string() },
+		];
+		let content: String = match encode_csv(&records) {
+			Ok(value) => value,
+			Err(EncodingError) => panic!("could not encode CSV"),
+		};
+		let decoded: Vec<D> = match decode_csv(&content) {
+			Ok(value) => value,
+			Err(DecodingError) => panic!("could not decode CSV"),
+		};
+
+		assert_eq!(decoded, records);
+	}
+	// endregion
+
+	// region: decode_csv_with_delimiter_00
+	#[test]
+	fn decode_csv_with_delimiter_00() {
+		let content: &str = "\
+			Hello, World!;\n\
+			;0\n\
+			a,b;42\n\
+		";
+		let records: Vec<B> = match decode_csv_with_delimiter(content, ';') {
+			Ok(value) => value,
+			Err(DecodingError) => panic!("could not decode CSV"),
+		};
+
+		assert_eq!(
+			records,
+			vec![
+				B { a: "Hello, World!".to_string(), b: None },
+				B { a: "".to_string(), b: Some(0) },
+				B { a: "a,b".to_string(), b: Some(42) },
+			]
+		);
+	}
+	// endregion
+
+	// region: decode_csv_with_delimiter_01
+	#[test]
+	fn decode_csv_with_delimiter_01() {
+		let content: &str = "Hello,42\n";
+
+		assert_eq!(decode_csv_with_delimiter::<B>(content, ';'), Err(DecodingError));
+	}
+	// endregion
+
+	// region: decode_csv_with_delimiter_02
+	#[test]
+	fn decode_csv_with_delimiter_02() {
+		let content: &str = "0\t1\t2\t3\t4\t5\t6\t7\t8\t9\t10\t11\n";
+		let records: Vec<C> = match decode_csv_with_delimiter(content, '\t') {
+			Ok(value) => value,
+			Err(DecodingError) => panic!("could not decode CSV"),
+		};
+
+		assert_eq!(
+			records,
+			vec![C { a: 0, b: 1, c: 2, d: 3, e: 4, f: 5, g: 6, h: 7, i: 8, j: 9, k: 10, l: 11 }]
+		);
+	}
+	// endregion
+
+	// region: encode_csv_with_delimiter_00
+	#[test]
+	fn encode_csv_with_delimiter_00() {
+		let records: Vec<B> = vec![
+			B { a: "May I have your attention, please?".to_string(), b: Some(7) },
+			B { a: "".to_string(), b: None },
+		];
+		let content: String = match encode_csv_with_delimiter(&records, ';') {
+			Ok(value) => value,
+			Err(EncodingError) => panic!("could not encode CSV"),
+		};
+
+		assert_eq!(content, "May I have your attention, please?;7\n;\n");
+	}
+	// endregion
+
+	// region: encode_csv_with_delimiter_01
+	#[test]
+	fn encode_csv_with_delimiter_01() {
+		let records: Vec<B> = vec![B { a: "Hello; World!".to_string(), b: None }];
+		let content: String = match encode_csv_with_delimiter(&records, ';') {
+			Ok(value) => value,
+			Err(EncodingError) => panic!("could not encode CSV"),
+		};
+
+		assert_eq!(content, "\"Hello; World!\";\n");
+	}
+	// endregion
+
+	// region: encode_csv_with_delimiter_02
+	#[test]
+	fn encode_csv_with_delimiter_02() {
+		let records: Vec<B> = vec![
+			B { a: "Never gonna give you up, never gonna let you down".to_string(), b: Some(98) },
+			B { a: "".to_string(), b: Some(0) },
+			B { a: "Hello".to_string(), b: None },
+		];
+		let content: String = match encode_csv_with_delimiter(&records, ';') {
+			Ok(value) => value,
+			Err(EncodingError) => panic!("could not encode CSV"),
+		};
+		let decoded: Vec<B> = match decode_csv_with_delimiter(&content, ';') {
+			Ok(value) => value,
+			Err(DecodingError) => panic!("could not decode CSV"),
+		};
+
+		assert_eq!(decoded, records);
+	}
+	// endregion
+
+	// region: to_aligned_table_00
+	#[test]
+	fn to_aligned_table_00() {
+		let records: Vec<A> = vec![];
+		let table: String = match to_aligned_table(&records) {
+			Ok(value) => value,
+			Err(EncodingError) => panic!("could not encode table"),
+		};
+
+		assert_eq!(table, "");
+	}
+	// endregion
+
+	// region: to_aligned_table_01
+	#[test]
+	fn to_aligned_table_01() {
+		let records: Vec<B> = vec![
+			B { a: "Hello".to_string(), b: None },
+			B { a: "".to_string(), b: Some(0) },
+			B { a: "World!".to_string(), b: Some(42) },
+		];
+		let table: String = match to_aligned_table(&records) {
+			Ok(value) => value,
+			Err(EncodingError) => panic!("could not encode table"),
+		};
+
+		assert_eq!(table, "Hello  | \n       | 0\nWorld! | 42\n");
+	}
+	// endregion
+
+	// region: to_aligned_table_02
+	#[test]
+	fn to_aligned_table_02() {
+		let records: Vec<C> = vec![
+			// region: records[0]
+			C { a: 66, b: 65, c: 63, d: 60, e: 56, f: 51, g: 45, h: 38, i: 30, j: 21, k: 11, l: 0 },
+			// endregion
+			// region: records[1]
+			C { a: 12, b: 10, c: 8, d: 6, e: 4, f: 2, g: 1, h: 3, i: 5, j: 7, k: 9, l: 11 },
+			// endregion
+			// region: records[2]
+			C {
+				a: 123,
+				b: 234,
+				c: 345,
+				d: 456,
+				e: 567,
+				f: 678,
+				g: -12,
+				h: -23,
+				i: -34,
+				j: -45,
+				k: -56,
+				l: -67,
+			},
+			// endregion
+		];
+		let table: String = match to_aligned_table(&records) {
+			Ok(value) => value,
+			Err(EncodingError) => panic!("could not encode table"),
+		};
+
+		assert_eq!(
+			table,
+			"\
+			66  | 65  | 63  | 60  | 56  | 51  | 45  | 38  | 30  | 21  | 11  | 0\n\
+			12  | 10  | 8   | 6   | 4   | 2   | 1   | 3   | 5   | 7   | 9   | 11\n\
+			123 | 234 | 345 | 456 | 567 | 678 | -12 | -23 | -34 | -45 | -56 | -67\n"
+		);
+	}
+	// endregion
+
+	// region: to_aligned_table_03
+	#[test]
+	fn to_aligned_table_03() {
+		let records: Vec<B> = vec![
+			B { a: "May I have your attention, please?".to_string(), b: None },
+			B { a: "Yes".to_string(), b: Some(42) },
+		];
+		let table: String = match to_aligned_table(&records) {
+			Ok(value) => value,
+			Err(EncodingError) => panic!("could not encode table"),
+		};
+
+		assert_eq!(
+			table,
+			"May I have your attention, please? | \nYes                                | 42\n"
+		);
+	}
+	// endregion
+
+	// region: looks_like_header_00
+	#[test]
+	fn looks_like_header_00() {
+		assert_eq!(looks_like_header(""), false);
+	}
+	// endregion
+
+	// region: looks_like_header_01
+	#[test]
+	fn looks_like_header_01() {
+		let content: &str = "\
+			66,65,63,60,56,51,45,38,30,21,11,0\n\
+			12,10,8,6,4,2,1,3,5,7,9,11\n\
+			123,234,345,456,567,678,-12,-23,-34,-45,-56,-67\n\
+		";
+
+		assert_eq!(looks_like_header(content), false);
+	}
+	// endregion
+
+	// region: looks_like_header_02
+	#[test]
+	fn looks_like_header_02() {
+		let content: &str = "\
+			a,b,c,d,e,f,g,h,i,j,k,l\n\
+			66,65,63,60,56,51,45,38,30,21,11,0\n\
+			12,10,8,6,4,2,1,3,5,7,9,11\n\
+			123,234,345,456,567,678,-12,-23,-34,-45,-56,-67\n\
+		";
+
+		assert_eq!(looks_like_header(content), true);
+	}
+	// endregion
+
+	// region: looks_like_header_03
+	#[test]
+	fn looks_like_header_03() {
+		let content: &str = "a,b,c,d,e,f,g,h,i,j,k,l\n";
+
+		assert_eq!(looks_like_header(content), false);
+	}
+	// endregion
+
+	// region: looks_like_header_04
+	#[test]
+	fn looks_like_header_04() {
+		let content: &str = "\
+			name,nickname\n\
+			Jonathan,Jojo\n\
+		";
+
+		assert_eq!(looks_like_header(content), false);
+	}
+	// endregion
+
+	// region: looks_like_header_05
+	#[test]
+	fn looks_like_header_05() {
+		let content: &str = "\
+			name,score\n\
+			Jonathan,\n\
+			Joseph,4.2\n\
+		";
+
+		assert_eq!(looks_like_header(content), true);
+	}
+	// endregion
+
+	// region: tuple_record_00
+	#[test]
+	fn tuple_record_00() {
+		let records: Vec<(u8, String)> = decode_csv("1,hello").unwrap();
+
+		assert_eq!(records, [(1, "hello".to_string())]);
+	}
+	// endregion
+
+	// region: tuple_record_01
+	#[test]
+	fn tuple_record_01() {
+		let records: Vec<(i32,)> = decode_csv("-42\n0\n42\n").unwrap();
+
+		assert_eq!(records, [(-42,), (0,), (42,)]);
+		assert_eq!(encode_csv(&records).unwrap(), "-42\n0\n42\n");
+	}
+	// endregion
+
+	// region: tuple_record_02
+	#[test]
+	fn tuple_record_02() {
+		let content: &str = "\
+			Jonathan,42,\n\
+			\"Dufour, Jonathan\",21,7\n\
+		";
+		let records: Vec<(String, u8, Option<u16>)> = decode_csv(content).unwrap();
+
+		assert_eq!(
+			records,
+			[("Jonathan".to_string(), 42, None), ("Dufour, Jonathan".to_string(), 21, Some(7))]
+		);
+		assert_eq!(encode_csv(&records).unwrap(), content);
+	}
+	// endregion
+
+	// region: tuple_record_03
+	#[test]
+	fn tuple_record_03() {
+		assert_eq!(decode_csv::<(u8, u8, u8)>("1,2"), Err(DecodingError));
+		assert_eq!(decode_csv::<(u8, u8, u8)>("1,2,3,4"), Err(DecodingError));
+		assert_eq!(decode_csv::<(u8, u8, u8)>("1,2,256"), Err(DecodingError));
+	}
+	// endregion
+
+	// region: tuple_record_04
+	#[test]
+	fn tuple_record_04() {
+		let records: Vec<(String, u8, u8)> =
+			decode_csv_with_delimiter("a;1;2\nb;3;4\n", ';').unwrap();
+
+		assert_eq!(records, [("a".to_string(), 1, 2), ("b".to_string(), 3, 4)]);
+		assert_eq!(encode_csv_with_delimiter(&records, ';').unwrap(), "a;1;2\nb;3;4\n");
+	}
+	// endregion
+
+	// region: tuple_record_05
+	#[test]
+	fn tuple_record_05() {
+		let records: Vec<(u8, String)> =
+			decode_csv_with_header("id,name\n1,Jonathan\n2,Joseph\n").unwrap();
+
+		assert_eq!(records, [(1, "Jonathan".to_string()), (2, "Joseph".to_string())]);
+	}
+	// endregion
+
+	// region: field_bool_00
+	#[test]
+	fn field_bool_00() {
+		assert_eq!(bool::decode("true"), Ok(true));
+		assert_eq!(bool::decode("false"), Ok(false));
+	}
+	// endregion
+
+	// region: field_bool_01
+	#[test]
+	fn field_bool_01() {
+		for field in ["", "TRUE", "False", "1", "0", "yes", " true", "true "] {
+			assert_eq!(bool::decode(field), Err(DecodingError));
+		}
+	}
+	// endregion
+
+	// region: field_char_00
+	#[test]
+	fn field_char_00() {
+		assert_eq!(char::decode("a"), Ok('a'));
+		assert_eq!(char::decode("ç"), Ok('ç'));
+		assert_eq!(char::decode("🦀"), Ok('🦀'));
+	}
+	// endregion
+
+	// region: field_char_01
+	#[test]
+	fn field_char_01() {
+		for field in ["", "ab", "a ", "🦀🦀"] {
+			assert_eq!(char::decode(field), Err(DecodingError));
+		}
+	}
+	// endregion
+
+	// region: field_bool_char_00
+	#[test]
+	fn field_bool_char_00() {
+		let content: &str = "\
+			true,a\n\
+			false,ç\n\
+			true,\",\"\n\
+			false,\"\"\"\"\n\
+		";
+		let records: Vec<E> = decode_csv(content).unwrap();
+
+		assert_eq!(
+			records,
+			[
+				E { a: true, b: 'a' },
+				E { a: false, b: 'ç' },
+				E { a: true, b: ',' },
+				E { a: false, b: '"' }
+			]
+		);
+		assert_eq!(encode_csv(&records).unwrap(), content);
+	}
+	// endregion
+
+	// region: field_bool_char_01
+	#[test]
+	fn field_bool_char_01() {
+		assert_eq!(decode_csv::<E>("yes,a\n"), Err(DecodingError));
+		assert_eq!(decode_csv::<E>("true,ab\n"), Err(DecodingError));
+		assert_eq!(decode_csv::<E>("true,\n"), Err(DecodingError));
+	}
+	// endregion
+
+	// region: field_float_00
+	#[test]
+	#[allow(clippy::approx_constant)]
+	fn field_float_00() {
+		let records: Vec<F> = decode_csv("3.14,2.0").unwrap();
+
+		assert_eq!(records, [F { a: 3.14, b: 2.0 }]);
+	}
+	// endregion
+
+	// region: field_float_01
+	#[test]
+	fn field_float_01() {
+		assert_eq!(decode_csv::<F>(",2.0"), Err(DecodingError));
+		assert_eq!(decode_csv::<F>("3.14,"), Err(DecodingError));
+	}
+	// endregion
+
+	// region: field_float_02
+	#[test]
+	fn field_float_02() {
+		for field in ["NaN", "inf", "-inf", "infinity", "1e999"] {
+			assert_eq!(f64::decode(field), Err(DecodingError));
+		}
+		assert_eq!(f32::decode("1e39"), Err(DecodingError));
+	}
+	// endregion
+
+	// region: field_float_03
+	#[test]
+	fn field_float_03() {
+		assert_eq!(f64::decode("-0.5"), Ok(-0.5));
+		assert_eq!(f64::decode("1e3"), Ok(1000.0));
+		assert_eq!(f32::decode("42"), Ok(42.0));
+	}
+	// endregion
+
+	// region: field_float_04
+	#[test]
+	fn field_float_04() {
+		let records: [F; 3] =
+			[F { a: 1.25, b: 2.0 }, F { a: -0.5, b: 1e-10 }, F { a: 0.0, b: f64::MAX }];
+		let content: String = encode_csv(&records).unwrap();
+
+		assert_eq!(decode_csv::<F>(&content).unwrap(), records);
+	}
+	// endregion
+
+	// region: field_float_05
+	#[test]
+	fn field_float_05() {
+		assert_eq!(encode_csv(&[F { a: f32::NAN, b: 0.0 }]), Err(EncodingError));
+		assert_eq!(encode_csv(&[F { a: 0.0, b: f64::INFINITY }]), Err(EncodingError));
 	}
 	// endregion
 }
@@ -1,15 +1,58 @@
 use crate::error::{DecodingError, EncodingError};
+use crate::field::Field;
 
 pub trait Record: Sized {
+	/// Decodes a record from its `str` representation to its concrete type value,
+	/// assuming its fields are separated by commas.
+	///
+	/// ### Parameters
+	/// * `line` - The line to decode.
+	///
+	/// ### Return
+	/// * `Ok(Self)` - The decoded record.
+	/// * `Err(DecodingError)` - The line could not be decoded.
+	fn decode(line: &str) -> Result<Self, DecodingError> {
+		Self::decode_with_delimiter(line, ',')
+	}
+
 	/// Decodes a record from its `str` representation to its concrete type value.
 	///
 	/// ### Parameters
 	/// * `line` - The line to decode.
+	/// * `delimiter` - The character separating the fields of the record.
 	///
 	/// ### Return
 	/// * `Ok(Self)` - The decoded record.
 	/// * `Err(DecodingError)` - The line could not be decoded.
-	fn decode(line: &str) -> Result<Self, DecodingError>;
+	fn decode_with_delimiter(line: &str, delimiter: char) -> Result<Self, DecodingError>;
+
+	/// Decodes a record from its fields, mapping each of them to the column it belongs to by name.
+	/// Columns that do not match any field of the record are ignored.
+	///
+	/// ### Parameters
+	/// * `headers` - The names of the columns, in order.
+	/// * `fields` - The fields of the record to decode, in the same order as `headers`.
+	///
+	/// ### Return
+	/// * `Ok(Self)` - The decoded record.
+	/// * `Err(DecodingError)` - The fields could not be decoded,
+	///   a required column is missing, or `fields` and `headers` have different lengths.
+	fn decode_by_header(headers: &[&str], fields: &[&str]) -> Result<Self, DecodingError>;
+
+	/// Encodes a record from its concrete type value to its `str` representation,
+	/// separating its fields by commas, and appends it to a target string.
+	/// Note that the resulting `str` representation is not terminated by a newline.
+	///
+	/// ### Parameters
+	/// * `self` - The record to encode.
+	/// * `target` - The string to append the encoded record to.
+	///
+	/// ### Return
+	/// * `Ok(())` - The record was successfully encoded.
+	/// * `Err(EncodingError)` - The record could not be encoded.
+	fn encode(self: &Self, target: &mut String) -> Result<(), EncodingError> {
+		self.encode_with_delimiter(target, ',')
+	}
 
 	/// Encodes a record from its concrete type value to its `str` representation,
 	/// and appends it to a target string.
@@ -18,19 +61,68 @@ pub trait Record: Sized {
 	/// ### Parameters
 	/// * `self` - The record to encode.
 	/// * `target` - The string to append the encoded record to.
+	/// * `delimiter` - The character separating the fields of the record.
 	///
 	/// ### Return
 	/// * `Ok(())` - The record was successfully encoded.
 	/// * `Err(EncodingError)` - The record could not be encoded.
-	fn encode(self: &Self, target: &mut String) -> Result<(), EncodingError>;
+	fn encode_with_delimiter(
+		self: &Self,
+		target: &mut String,
+		delimiter: char,
+	) -> Result<(), EncodingError>;
+}
+
+/// Splits a record from its `str` representation into its fields.
+/// A field may be wrapped in double quotes, in which case it may contain the delimiter,
+/// newlines, and double quotes escaped as `""`.
+///
+/// ### Parameters
+/// * `line` - The record to split.
+/// * `delimiter` - The character separating the fields of the record.
+///
+/// ### Return
+/// * `Ok(Vec<String>)` - The unquoted fields of the record.
+/// * `Err(DecodingError)` - A quoted field is not terminated,
+///   or its closing quote is not followed by the delimiter.
+pub fn split_fields(line: &str, delimiter: char) -> Result<Vec<String>, DecodingError> {
+	let mut fields: Vec<String> = Vec::new();
+	let mut field: String = String::new();
+	let mut chars: std::iter::Peekable<std::str::Chars<'_>> = line.chars().peekable();
+	let mut is_quoted: bool = chars.next_if_eq(&'"').is_some();
+
+	while let Some(c) = chars.next() {
+		if is_quoted && c == '"' {
+			if chars.next_if_eq(&'"').is_some() {
+				field.push('"');
+				continue;
+			}
+			match chars.peek() {
+				Some(&next) if next != delimiter => return Err(DecodingError),
+				_ => is_quoted = false,
+			}
+		} else if !is_quoted && c == delimiter {
+			fields.push(std::mem::take(&mut field));
+			is_quoted = chars.next_if_eq(&'"').is_some();
+		} else {
+			field.push(c);
+		}
+	}
+	if is_quoted {
+		return Err(DecodingError);
+	}
+	fields.push(field);
+
+	Ok(fields)
 }
 
 #[macro_export]
 macro_rules! impl_record_for_struct {
 	($struct_identifier:ident {}) => {
 		impl Record for $struct_identifier {
-			fn decode(line: &str) -> Result<Self, DecodingError> {
-				let mut fields: std::str::Split<'_, char> = line.split(',');
+			fn decode_with_delimiter(line: &str, delimiter: char) -> Result<Self, DecodingError> {
+				let mut fields: std::vec::IntoIter<String> =
+					$crate::record::split_fields(line, delimiter)?.into_iter();
 
 				match fields.next() {
 					Some(field) if field.is_empty() => (),
@@ -43,7 +135,19 @@ macro_rules! impl_record_for_struct {
 				Ok($struct_identifier {})
 			}
 
-			fn encode(&self, _target: &mut String) -> Result<(), EncodingError> {
+			fn decode_by_header(headers: &[&str], fields: &[&str]) -> Result<Self, DecodingError> {
+				if fields.len() != headers.len() {
+					return Err(DecodingError);
+				}
+
+				Ok($struct_identifier {})
+			}
+
+			fn encode_with_delimiter(
+				&self,
+				_target: &mut String,
+				_delimiter: char,
+			) -> Result<(), EncodingError> {
 				Ok(())
 			}
 		}
@@ -55,16 +159,17 @@ macro_rules! impl_record_for_struct {
 		}
 	) => {
 		impl Record for $struct_identifier {
-			fn decode(line: &str) -> Result<Self, DecodingError> {
-				let mut fields: std::str::Split<'_, char> = line.split(',');
+			fn decode_with_delimiter(line: &str, delimiter: char) -> Result<Self, DecodingError> {
+				let mut fields: std::vec::IntoIter<String> =
+					$crate::record::split_fields(line, delimiter)?.into_iter();
 
 				let $first_field_identifier: $first_field_type = match fields.next() {
-					Some(field) => Field::decode(field)?,
+					Some(field) => Field::decode(&field)?,
 					None => return Err(DecodingError),
 				};
 				$(
 					let $next_field_identifier: $next_field_type = match fields.next() {
-						Some(field) => Field::decode(field)?,
+						Some(field) => Field::decode(&field)?,
 						None => return Err(DecodingError),
 					};
 				)*
@@ -78,11 +183,40 @@ macro_rules! impl_record_for_struct {
 				})
 			}
 
-			fn encode(self: &Self, target: &mut String) -> Result<(), EncodingError> {
-				Field::encode(&self.$first_field_identifier, target)?;
+			fn decode_by_header(headers: &[&str], fields: &[&str]) -> Result<Self, DecodingError> {
+				if fields.len() != headers.len() {
+					return Err(DecodingError);
+				}
+
+				let column = |name: &str| -> Result<&str, DecodingError> {
+					match headers.iter().position(|header: &&str| *header == name) {
+						Some(i) => Ok(fields[i]),
+						None => Err(DecodingError),
+					}
+				};
+
+				let $first_field_identifier: $first_field_type =
+					Field::decode(column(stringify!($first_field_identifier))?)?;
 				$(
-					target.push(',');
-					Field::encode(&self.$next_field_identifier, target)?;
+					let $next_field_identifier: $next_field_type =
+						Field::decode(column(stringify!($next_field_identifier))?)?;
+				)*
+
+				Ok($struct_identifier {
+					$first_field_identifier,
+					$($next_field_identifier),*
+				})
+			}
+
+			fn encode_with_delimiter(
+				self: &Self,
+				target: &mut String,
+				delimiter: char,
+			) -> Result<(), EncodingError> {
+				Field::encode(&self.$first_field_identifier, target, delimiter)?;
+				$(
+					target.push(delimiter);
+					Field::encode(&self.$next_field_identifier, target, delimiter)?;
 				)*
 
 				Ok(())
@@ -90,3 +224,61 @@ macro_rules! impl_record_for_struct {
 		}
 	};
 }
+
+/// Implements `Record` for a tuple, each of its elements being mapped to a column by position.
+macro_rules! impl_record_for_tuple {
+	(@decode $fields:ident, $($type:ident)+) => {{
+		let record: Self = (
+			$(
+				match $fields.next() {
+					Some(field) => $type::decode(field)?,
+					None => return Err(DecodingError),
+				},
+			)+
+		);
+
+		if $fields.next().is_some() {
+			return Err(DecodingError);
+		}
+
+		Ok(record)
+	}};
+	($first_type:ident $first_index:tt $(, $next_type:ident $next_index:tt)*) => {
+		impl<$first_type: Field $(, $next_type: Field)*> Record for ($first_type, $($next_type,)*) {
+			fn decode_with_delimiter(line: &str, delimiter: char) -> Result<Self, DecodingError> {
+				let fields: Vec<String> = split_fields(line, delimiter)?;
+				let mut fields: std::slice::Iter<'_, String> = fields.iter();
+
+				impl_record_for_tuple!(@decode fields, $first_type $($next_type)*)
+			}
+
+			fn decode_by_header(headers: &[&str], fields: &[&str]) -> Result<Self, DecodingError> {
+				if fields.len() != headers.len() {
+					return Err(DecodingError);
+				}
+
+				let mut fields: std::slice::Iter<'_, &str> = fields.iter();
+
+				impl_record_for_tuple!(@decode fields, $first_type $($next_type)*)
+			}
+
+			fn encode_with_delimiter(
+				self: &Self,
+				target: &mut String,
+				delimiter: char,
+			) -> Result<(), EncodingError> {
+				self.$first_index.encode(target, delimiter)?;
+				$(
+					target.push(delimiter);
+					self.$next_index.encode(target, delimiter)?;
+				)*
+
+				Ok(())
+			}
+		}
+	};
+}
+
+impl_record_for_tuple!(A 0);
+impl_record_for_tuple!(A 0, B 1);
+impl_record_for_tuple!(A 0, B 1, C 2);
@@ -1,3 +1,44 @@
+/// Parses a string as a collection of positive integers,
+/// and compute the sum of all the numbers in that match a predicate.
+///
+/// The positive integers contained in the string are separated by whitespaces.
+/// (['\t', '\n', '\v', '\f', '\r', ' '])
+///
+/// Whenever a word is not a valid positive integer, it is ignored.
+///
+/// ### Type parameters
+/// * `F` - The type of the predicate.
+///
+/// ### Parameters
+/// * `s` - The string to parse the positive integers from.
+/// * `pred` - The predicate the positive integers must match to be summed.
+///
+/// ### Return
+/// The sum of all positive integers in the string that match the predicate.
+///
+/// ### Example
+/// ```
+/// use ex02::sum_of_matching;
+///
+/// assert_eq!(sum_of_matching("1 2 3 4 5", |n| n > 2), 12);
+/// ```
+pub fn sum_of_matching<F: Fn(u32) -> bool>(s: &str, pred: F) -> u32 {
+	s.split_whitespace().filter_map(|word| word.parse::<u32>().ok()).filter(|&n| pred(n)).sum()
+}
+
+/// Alias of `sum_of_matching`, see its documentation.
+///
+/// ### Example
+/// ```
+/// use ex02::sum_where;
+///
+/// assert_eq!(sum_where("3 6 7 9", |n| n % 3 == 0), 18);
+/// ```
+#[inline(always)]
+pub fn sum_where<F: Fn(u32) -> bool>(s: &str, pred: F) -> u32 {
+	sum_of_matching(s, pred)
+}
+
 /// Parses a string as a collection of positive integers, and compute the sum of all odd numbers in.
 ///
 /// The positive integers contained in the string are separated by whitespaces.
@@ -24,10 +65,70 @@
 /// 34 55 89"), 188);
 /// ```
 pub fn sum_of_odds(s: &str) -> u32 {
-	s.split_whitespace().filter_map(|word| word.parse::<u32>().ok()).filter(|&n| n % 2 == 1).sum()
+	sum_of_matching(s, |n| n % 2 == 1)
+}
+
+/// Parses a string as a collection of positive integers, and compute the sum of all even numbers in.
+///
+/// The positive integers contained in the string are separated by whitespaces.
+/// (['\t', '\n', '\v', '\f', '\r', ' '])
+///
+/// Whenever a word is not a valid positive integer, it is ignored.
+///
+/// ### Parameters
+/// * `s` - The string to parse the positive integers from.
+///
+/// ### Return
+/// The sum of all even positive integers in the string.
+///
+/// ### Example
+/// ```
+/// use ex02::sum_of_evens;
+///
+/// assert_eq!(sum_of_evens("1 2 3 4"), 6);
+/// assert_eq!(sum_of_evens("\t1\t0\t1\t0\t1\t0\t"), 0);
+/// assert_eq!(sum_of_evens("
+///  0  1  1
+///  2  3  5
+///  8 13 21
+/// 34 55 89"), 44);
+/// ```
+pub fn sum_of_evens(s: &str) -> u32 {
+	sum_of_matching(s, |n| n % 2 == 0)
 }
 
 use std::str::FromStr;
+/// Parses a string as a collection of values, failing on the first word that cannot be parsed.
+///
+/// The values contained in the string are separated by whitespaces.
+/// (['\t', '\n', '\v', '\f', '\r', ' '])
+///
+/// ### Type parameters
+/// * `T` - The type of the values to parse.
+///
+/// ### Parameters
+/// * `s` - The string to parse the values from.
+///
+/// ### Return
+/// * `Ok(Vec<T>)` - The parsed values, in order.
+/// * `Err(String)` - A message naming the first word that could not be parsed.
+///
+/// ### Example
+/// ```
+/// use ex02::parse_all;
+///
+/// assert_eq!(parse_all::<u32>("1 2 3"), Ok(vec![1, 2, 3]));
+/// assert_eq!(parse_all::<u32>("1 x"), Err("could not parse \"x\"".to_string()));
+/// ```
+pub fn parse_all<T: FromStr>(s: &str) -> Result<Vec<T>, String> {
+	s.split_whitespace()
+		.map(|word| match word.parse::<T>() {
+			Ok(parsed) => Ok(parsed),
+			Err(_) => Err(format!("could not parse {:?}", word)),
+		})
+		.collect()
+}
+
 /// Parses a string as a collection of pairs.
 ///
 /// Each line of the string is a pair, where the first element is the key,
@@ -68,6 +169,70 @@ pub fn create_pairs<T: FromStr>(s: &str) -> Vec<(&str, T)> {
 		.collect()
 }
 
+/// Parses a string as a collection of pairs, whose keys are owned.
+///
+/// The string is parsed exactly as `create_pairs` does,
+/// but the resulting pairs do not borrow from it.
+///
+/// ### Parameters
+/// * `s` - The string to parse the pairs from.
+///
+/// ### Return
+/// A `Vec` of pairs.
+///
+/// ### Example
+/// ```
+/// use ex02::create_pairs_owned;
+///
+/// let pairs: Vec<(String, u32)> = {
+/// 	let s: String = String::from(" foo : 0 \n bar : 1 \n");
+///
+/// 	create_pairs_owned(&s)
+/// };
+///
+/// assert_eq!(pairs, vec![("foo".to_string(), 0), ("bar".to_string(), 1)]);
+/// ```
+pub fn create_pairs_owned<T: FromStr>(s: &str) -> Vec<(String, T)> {
+	create_pairs(s).into_iter().map(|(key, value)| (key.to_string(), value)).collect()
+}
+
+/// Parses a string as a collection of pairs, failing on the first value that cannot be parsed.
+///
+/// Each line of the string is a pair, where the first element is the key,
+/// and the second element is the value.
+/// The two elements are separated by a single colon (':').
+/// Both fields are trimmed before being parsed.
+///
+/// Whenever a line does not contain a colon, it is ignored.
+///
+/// ### Parameters
+/// * `s` - The string to parse the pairs from.
+///
+/// ### Return
+/// * `Ok(Vec<(&str, T)>)` - The parsed pairs.
+/// * `Err((usize, &str))` - The 1-based number of the first line whose value could not be parsed,
+///   along with the trimmed value.
+///
+/// ### Example
+/// ```
+/// use ex02::create_pairs_strict;
+///
+/// assert_eq!(create_pairs_strict::<u32>(" foo : 0 \n bar : 1 \n"), Ok(vec![("foo", 0), ("bar", 1)]));
+/// assert_eq!(create_pairs_strict::<u32>("a:1\nb:x"), Err((2, "x")));
+/// ```
+pub fn create_pairs_strict<T: FromStr>(s: &str) -> Result<Vec<(&str, T)>, (usize, &str)> {
+	s.lines()
+		.enumerate()
+		.filter_map(|(i, line)| {
+			line.split_once(':').map(|(key, value)| (i + 1, key.trim(), value.trim()))
+		})
+		.map(|(line_number, key, value)| match value.parse::<T>() {
+			Ok(parsed) => Ok((key, parsed)),
+			Err(_) => Err((line_number, value)),
+		})
+		.collect()
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -220,6 +385,235 @@ mod tests {
 	}
 	// endregion
 
+	// region: sum_of_evens_00
+	#[test]
+	fn sum_of_evens_00() {
+		const S: &str = "";
+		const EXPECTED: u32 = 0;
+
+		assert_eq!(sum_of_evens(S), EXPECTED);
+	}
+	// endregion
+
+	// region: sum_of_evens_01
+	#[test]
+	fn sum_of_evens_01() {
+		const S: &str = "0";
+		const EXPECTED: u32 = 0;
+
+		assert_eq!(sum_of_evens(S), EXPECTED);
+	}
+	// endregion
+
+	// region: sum_of_evens_02
+	#[test]
+	fn sum_of_evens_02() {
+		const S: &str = "2";
+		const EXPECTED: u32 = 2;
+
+		assert_eq!(sum_of_evens(S), EXPECTED);
+	}
+	// endregion
+
+	// region: sum_of_evens_03
+	#[test]
+	fn sum_of_evens_03() {
+		const S: &str = " 1 2 3 ";
+		const EXPECTED: u32 = 2;
+
+		assert_eq!(sum_of_evens(S), EXPECTED);
+	}
+	// endregion
+
+	// region: sum_of_evens_04
+	#[test]
+	fn sum_of_evens_04() {
+		const S: &str = "12346";
+		const EXPECTED: u32 = 12346;
+
+		assert_eq!(sum_of_evens(S), EXPECTED);
+	}
+	// endregion
+
+	// region: sum_of_evens_05
+	#[test]
+	fn sum_of_evens_05() {
+		const S: &str = "-1 +2 +3 -4 -5 +6 +7";
+		const EXPECTED: u32 = 8;
+
+		assert_eq!(sum_of_evens(S), EXPECTED);
+	}
+	// endregion
+
+	// region: sum_of_evens_06
+	#[test]
+	fn sum_of_evens_06() {
+		const S: &str = "2147483646     2147483648  1";
+		const EXPECTED: u32 = 4294967294;
+
+		assert_eq!(sum_of_evens(S), EXPECTED);
+	}
+	// endregion
+
+	// region: sum_of_evens_07
+	#[test]
+	fn sum_of_evens_07() {
+		const S: &str = "\x0900\x0a11\x0b22\x0c33\x0d44\x2055";
+		const EXPECTED: u32 = 66;
+
+		assert_eq!(sum_of_evens(S), EXPECTED);
+	}
+	// endregion
+
+	// region: sum_of_evens_08
+	#[test]
+	fn sum_of_evens_08() {
+		const S: &str =
+			"20 errors occured at line 4\nDon't panic, and retry in about 5 to 15 minutes";
+		const EXPECTED: u32 = 24;
+
+		assert_eq!(sum_of_evens(S), EXPECTED);
+	}
+	// endregion
+
+	// region: sum_of_matching_00
+	#[test]
+	fn sum_of_matching_00() {
+		const S: &str = "1 2 3 4 5";
+		const EXPECTED: u32 = 0;
+
+		assert_eq!(sum_of_matching(S, |_| false), EXPECTED);
+	}
+	// endregion
+
+	// region: sum_of_matching_01
+	#[test]
+	fn sum_of_matching_01() {
+		const S: &str = "1 2 3 4 5";
+		const EXPECTED: u32 = 12;
+
+		assert_eq!(sum_of_matching(S, |n| n > 2), EXPECTED);
+	}
+	// endregion
+
+	// region: sum_of_matching_02
+	#[test]
+	fn sum_of_matching_02() {
+		const S: &str = "a 1 b 2 -3 c4 5d";
+		const EXPECTED: u32 = 3;
+
+		assert_eq!(sum_of_matching(S, |_| true), EXPECTED);
+	}
+	// endregion
+
+	// region: sum_where_00
+	#[test]
+	fn sum_where_00() {
+		const S: &str = "3 6 7 9";
+		const EXPECTED: u32 = 18;
+
+		assert_eq!(sum_where(S, |n| n % 3 == 0), EXPECTED);
+	}
+	// endregion
+
+	// region: sum_where_01
+	#[test]
+	fn sum_where_01() {
+		const S: &str = "";
+		const EXPECTED: u32 = 0;
+
+		assert_eq!(sum_where(S, |_| true), EXPECTED);
+	}
+	// endregion
+
+	// region: sum_where_02
+	#[test]
+	fn sum_where_02() {
+		const S: &str = "0 1 -2 +3 x4 5 6 7 8 9 10";
+		const EXPECTED: u32 = 18;
+
+		assert_eq!(sum_where(S, |n| n % 3 == 0), EXPECTED);
+	}
+	// endregion
+
+	// region: sum_where_03
+	#[test]
+	fn sum_where_03() {
+		const S: &str = "\x0900\x0a11\x0b22\x0c33\x0d44\x2055";
+
+		assert_eq!(sum_where(S, |n| n % 2 == 1), sum_of_odds(S));
+		assert_eq!(sum_where(S, |n| n % 2 == 0), sum_of_evens(S));
+	}
+	// endregion
+
+	// region: parse_all_00
+	#[test]
+	fn parse_all_00() {
+		const S: &str = "";
+		let expected: Result<Vec<u32>, String> = Ok(vec![]);
+
+		assert_eq!(parse_all::<u32>(S), expected);
+	}
+	// endregion
+
+	// region: parse_all_01
+	#[test]
+	fn parse_all_01() {
+		const S: &str = "1 2 3";
+		let expected: Result<Vec<u32>, String> = Ok(vec![1, 2, 3]);
+
+		assert_eq!(parse_all::<u32>(S), expected);
+	}
+	// endregion
+
+	// region: parse_all_02
+	#[test]
+	fn parse_all_02() {
+		const S: &str = "1 x";
+
+		match parse_all::<u32>(S) {
+			Ok(_) => panic!("\"x\" should not be parsed"),
+			Err(message) => assert_eq!(message.contains("\"x\""), true),
+		}
+	}
+	// endregion
+
+	// region: parse_all_03
+	#[test]
+	fn parse_all_03() {
+		const S: &str = "\x09-1\x0a+2\x0b-3\x0c+4\x0d-5\x20+6";
+		let expected: Result<Vec<i8>, String> = Ok(vec![-1, 2, -3, 4, -5, 6]);
+
+		assert_eq!(parse_all::<i8>(S), expected);
+	}
+	// endregion
+
+	// region: parse_all_04
+	#[test]
+	fn parse_all_04() {
+		const S: &str = "42 256 -1 foo";
+
+		match parse_all::<u8>(S) {
+			Ok(_) => panic!("\"256\" should not be parsed"),
+			Err(message) => {
+				assert_eq!(message.contains("\"256\""), true);
+				assert_eq!(message.contains("\"-1\""), false);
+				assert_eq!(message.contains("\"foo\""), false);
+			}
+		}
+	}
+	// endregion
+
+	// region: parse_all_05
+	#[test]
+	fn parse_all_05() {
+		const S: &str = "  ";
+		let expected: Result<Vec<B>, String> = Ok(vec![]);
+
+		assert_eq!(parse_all::<B>(S), expected);
+	}
+	// endregion
+
 	// region: create_pairs_00
 	#[test]
 	fn create_pairs_00() {
@@ -266,4 +660,106 @@ Have fun!:255 -0\n";
 		assert_eq!(create_pairs::<C>(S), expected);
 	}
 	// endregion
+
+	// region: create_pairs_owned_00
+	#[test]
+	fn create_pairs_owned_00() {
+		const S: &str = "";
+		let expected: Vec<(String, A)> = vec![];
+
+		assert_eq!(create_pairs_owned::<A>(S), expected);
+	}
+	// endregion
+
+	// region: create_pairs_owned_01
+	#[test]
+	fn create_pairs_owned_01() {
+		let pairs: Vec<(String, C)> = {
+			let s: String = ["Welcome :42,-12", "to", "summoners:3,5", "rift:+218,118"].join("\n");
+
+			create_pairs_owned::<C>(&s)
+		};
+		let expected: Vec<(String, C)> = vec![
+			("Welcome".to_string(), C { a: 42, b: -12 }),
+			("summoners".to_string(), C { a: 3, b: 5 }),
+			("rift".to_string(), C { a: 218, b: 118 }),
+		];
+
+		assert_eq!(pairs, expected);
+	}
+	// endregion
+
+	// region: create_pairs_owned_02
+	#[test]
+	fn create_pairs_owned_02() {
+		const S: &str = "\
+\t\nWelcome \r :\x0b42 , -12  \n\
+to  \t  \n\
+summoners   :3 ,5\x0b  \r \n\
+  \x0crift:  \t+218, 118\n\
+Good luck!:-1,+128\n\
+Have fun!:255 -0\n";
+		let expected: Vec<(String, C)> = vec![
+			("Welcome".to_string(), C { a: 42, b: -12 }),
+			("summoners".to_string(), C { a: 3, b: 5 }),
+			("rift".to_string(), C { a: 218, b: 118 }),
+		];
+
+		assert_eq!(create_pairs_owned::<C>(S), expected);
+	}
+	// endregion
+
+	// region: create_pairs_strict_00
+	#[test]
+	fn create_pairs_strict_00() {
+		const S: &str = "";
+		let expected: Result<Vec<(&str, A)>, (usize, &str)> = Ok(vec![]);
+
+		assert_eq!(create_pairs_strict::<A>(S), expected);
+	}
+	// endregion
+
+	// region: create_pairs_strict_01
+	#[test]
+	fn create_pairs_strict_01() {
+		const S: &str = "a:1\nb:x";
+		let expected: Result<Vec<(&str, u32)>, (usize, &str)> = Err((2, "x"));
+
+		assert_eq!(create_pairs_strict::<u32>(S), expected);
+	}
+	// endregion
+
+	// region: create_pairs_strict_02
+	#[test]
+	fn create_pairs_strict_02() {
+		const S: &str = "\
+\t\nWelcome \r :\x0b42 , -12  \n\
+to  \t  \n\
+summoners   :3 ,5\x0b  \r \n\
+  \x0crift:  \t+218, 118\n";
+		let expected: Result<Vec<(&str, C)>, (usize, &str)> = Ok(vec![
+			("Welcome", C { a: 42, b: -12 }),
+			("summoners", C { a: 3, b: 5 }),
+			("rift", C { a: 218, b: 118 }),
+		]);
+
+		assert_eq!(create_pairs_strict::<C>(S), expected);
+	}
+	// endregion
+
+	// region: create_pairs_strict_03
+	#[test]
+	fn create_pairs_strict_03() {
+		const S: &str = "\
+\t\nWelcome \r :\x0b42 , -12  \n\
+to  \t  \n\
+summoners   :3 ,5\x0b  \r \n\
+  \x0crift:  \t+218, 118\n\
+Good luck!: -1,+128 \n\
+Have fun!:255 -0\n";
+		let expected: Result<Vec<(&str, C)>, (usize, &str)> = Err((6, "-1,+128"));
+
+		assert_eq!(create_pairs_strict::<C>(S), expected);
+	}
+	// endregion
 }
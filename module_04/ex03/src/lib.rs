@@ -1,11 +1,24 @@
+use std::iter::FusedIterator;
+
+/// The direction in which the elements yielded by an Increasing iterator must evolve.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Monotonic {
+	Increasing,
+	Decreasing,
+}
+
 #[derive(Debug)]
-pub struct Increasing<I>
+pub struct Increasing<I, K = <I as Iterator>::Item, F = fn(&<I as Iterator>::Item) -> K>
 where
 	I: Iterator,
-	<I as Iterator>::Item: Clone + PartialOrd,
+	K: PartialOrd,
+	F: FnMut(&I::Item) -> K,
 {
 	inner: I,
-	previous: Option<I::Item>,
+	previous: Option<K>,
+	key: F,
+	mode: Monotonic,
+	is_strict: bool,
 }
 
 impl<I> Increasing<I>
@@ -37,19 +50,137 @@ where
 	where
 		C: IntoIterator<IntoIter = I>,
 	{
-		Self { inner: collection.into_iter(), previous: None }
+		Self {
+			inner: collection.into_iter(),
+			previous: None,
+			key: Clone::clone,
+			mode: Monotonic::Increasing,
+			is_strict: true,
+		}
+	}
+
+	/// Creates a new Increasing iterator instance and initializes its attributes.
+	/// The newly created Increasing iterator instance will iterate over a given collection
+	/// skipping every element that is less than
+	/// the element that this same iterator was previously on,
+	/// thus keeping runs of equal elements.
+	///
+	/// ### Type parameters
+	/// - `C`: the type of the collection to iterate over.
+	///
+	/// ### Parameters
+	/// - `collection`: the collection to iterate over.
+	///
+	/// ### Return
+	/// The newly created Increasing iterator instance.
+	///
+	/// ### Example
+	/// ```
+	/// use ex03::Increasing;
+	///
+	/// let mut it = Increasing::non_strict([1, 2, 2, 1, 3]);
+	///
+	/// assert_eq!(it.next(), Some(1));
+	/// assert_eq!(it.next(), Some(2));
+	/// assert_eq!(it.next(), Some(2));
+	/// assert_eq!(it.next(), Some(3));
+	/// assert_eq!(it.next(), None);
+	/// ```
+	pub fn non_strict<C>(collection: C) -> Self
+	where
+		C: IntoIterator<IntoIter = I>,
+	{
+		Self {
+			inner: collection.into_iter(),
+			previous: None,
+			key: Clone::clone,
+			mode: Monotonic::Increasing,
+			is_strict: false,
+		}
 	}
 }
 
-impl<I> Iterator for Increasing<I>
+impl<I, K, F> Increasing<I, K, F>
 where
 	I: Iterator,
-	<I as Iterator>::Item: Clone + PartialOrd,
+	K: PartialOrd,
+	F: FnMut(&I::Item) -> K,
+{
+	/// Creates a new Increasing iterator instance and initializes its attributes.
+	/// The newly created Increasing iterator instance will iterate over a given collection
+	/// skipping every element whose key is not strictly greater than
+	/// the key of the element that this same iterator was previously on.
+	///
+	/// ### Type parameters
+	/// - `C`: the type of the collection to iterate over.
+	///
+	/// ### Parameters
+	/// - `collection`: the collection to iterate over.
+	/// - `key`: the function computing the key of each element.
+	///
+	/// ### Return
+	/// The newly created Increasing iterator instance.
+	///
+	/// ### Example
+	/// ```
+	/// use ex03::Increasing;
+	///
+	/// let mut it = Increasing::by([('a', 1), ('b', 0), ('c', 2)], |&(_, n)| n);
+	///
+	/// assert_eq!(it.next(), Some(('a', 1)));
+	/// assert_eq!(it.next(), Some(('c', 2)));
+	/// assert_eq!(it.next(), None);
+	/// ```
+	pub fn by<C>(collection: C, key: F) -> Self
+	where
+		C: IntoIterator<IntoIter = I>,
+	{
+		Self {
+			inner: collection.into_iter(),
+			previous: None,
+			key,
+			mode: Monotonic::Increasing,
+			is_strict: true,
+		}
+	}
+
+	/// Sets the direction in which the keys of the elements yielded
+	/// by the calling Increasing iterator instance must evolve.
+	///
+	/// ### Parameters
+	/// - `mode`: the direction in which the keys must evolve.
+	///
+	/// ### Return
+	/// The calling Increasing iterator instance, with its mode updated.
+	///
+	/// ### Example
+	/// ```
+	/// use ex03::{Increasing, Monotonic};
+	///
+	/// let mut it = Increasing::new([3, 1, 2, 0]).with_mode(Monotonic::Decreasing);
+	///
+	/// assert_eq!(it.next(), Some(3));
+	/// assert_eq!(it.next(), Some(1));
+	/// assert_eq!(it.next(), Some(0));
+	/// assert_eq!(it.next(), None);
+	/// ```
+	pub fn with_mode(self: Self, mode: Monotonic) -> Self {
+		Self { mode, ..self }
+	}
+}
+
+impl<I, K, F> Iterator for Increasing<I, K, F>
+where
+	I: Iterator,
+	K: PartialOrd,
+	F: FnMut(&I::Item) -> K,
 {
 	type Item = I::Item;
 
-	/// Advances the iterator to the next element that is strictly greater than
-	/// the element that this same iterator was previously on.
+	/// Advances the iterator to the next element whose key is strictly greater than
+	/// (or strictly less than, in decreasing mode)
+	/// the key of the element that this same iterator was previously on.
+	/// If the iterator is not strict, keys equal to the previous one are accepted as well.
 	///
 	/// ### Return
 	/// * `Some(<I::Item>)` - The next element that fits the mentioned constraint.
@@ -69,23 +200,59 @@ where
 	fn next(self: &mut Self) -> Option<Self::Item> {
 		match self.previous.take() {
 			Some(previous) => {
-				while let Some(next) = self.inner.next() {
-					if next > previous {
-						self.previous = Some(next);
-						return self.previous.clone();
+				for next in self.inner.by_ref() {
+					let key: K = (self.key)(&next);
+					let is_monotonic: bool = match (self.mode, self.is_strict) {
+						(Monotonic::Increasing, true) => key > previous,
+						(Monotonic::Increasing, false) => key >= previous,
+						(Monotonic::Decreasing, true) => key < previous,
+						(Monotonic::Decreasing, false) => key <= previous,
+					};
+
+					if is_monotonic {
+						self.previous = Some(key);
+						return Some(next);
 					}
 				}
-				self.previous = None;
 
 				None
 			}
 			None => {
-				self.previous = self.inner.next();
+				let next: I::Item = self.inner.next()?;
+
+				self.previous = Some((self.key)(&next));
 
-				self.previous.clone()
+				Some(next)
 			}
 		}
 	}
+
+	/// Gives the bounds on the remaining length of the iterator.
+	/// As any remaining element may be skipped, the lower bound is always 0,
+	/// while the upper bound is the one of the inner iterator.
+	///
+	/// ### Return
+	/// A tuple containing the lower bound and the eventual upper bound of the remaining length.
+	///
+	/// ### Example
+	/// ```
+	/// use ex03::Increasing;
+	///
+	/// let it = Increasing::new([1, 2, 2, 3, 3, 3]);
+	///
+	/// assert_eq!(it.size_hint(), (0, Some(6)));
+	/// ```
+	fn size_hint(self: &Self) -> (usize, Option<usize>) {
+		(0, self.inner.size_hint().1)
+	}
+}
+
+impl<I, K, F> FusedIterator for Increasing<I, K, F>
+where
+	I: FusedIterator,
+	K: PartialOrd,
+	F: FnMut(&I::Item) -> K,
+{
 }
 
 #[cfg(test)]
@@ -98,7 +265,10 @@ mod tests {
 		<I as Iterator>::Item: Clone + PartialOrd,
 	{
 		fn eq(self: &Self, rhs: &Self) -> bool {
-			self.inner.clone().eq(rhs.inner.clone()) && self.previous.eq(&rhs.previous)
+			self.inner.clone().eq(rhs.inner.clone())
+				&& self.previous.eq(&rhs.previous)
+				&& self.mode.eq(&rhs.mode)
+				&& self.is_strict.eq(&rhs.is_strict)
 		}
 	}
 
@@ -107,8 +277,15 @@ mod tests {
 	fn new_00() {
 		let a: [u8; 0] = [];
 		let it: Increasing<std::array::IntoIter<u8, 0>> = Increasing::new(a);
-
-		assert_eq!(it, Increasing { inner: a.into_iter(), previous: None });
+		let expected: Increasing<std::array::IntoIter<u8, 0>> = Increasing {
+			inner: a.into_iter(),
+			previous: None,
+			key: Clone::clone,
+			mode: Monotonic::Increasing,
+			is_strict: true,
+		};
+
+		assert_eq!(it, expected);
 	}
 	// endregion
 
@@ -117,8 +294,15 @@ mod tests {
 	fn new_01() {
 		let a: [u16; 1] = [567];
 		let it: Increasing<std::array::IntoIter<u16, 1>> = Increasing::new(a);
-
-		assert_eq!(it, Increasing { inner: a.into_iter(), previous: None });
+		let expected: Increasing<std::array::IntoIter<u16, 1>> = Increasing {
+			inner: a.into_iter(),
+			previous: None,
+			key: Clone::clone,
+			mode: Monotonic::Increasing,
+			is_strict: true,
+		};
+
+		assert_eq!(it, expected);
 	}
 	// endregion
 
@@ -127,8 +311,15 @@ mod tests {
 	fn new_02() {
 		let v: Vec<u32> = vec![0, 1, 2, 3, 4, 3, 2, 1, 0];
 		let it: Increasing<std::vec::IntoIter<u32>> = Increasing::new(v.clone());
-
-		assert_eq!(it, Increasing { inner: v.into_iter(), previous: None });
+		let expected: Increasing<std::vec::IntoIter<u32>> = Increasing {
+			inner: v.into_iter(),
+			previous: None,
+			key: Clone::clone,
+			mode: Monotonic::Increasing,
+			is_strict: true,
+		};
+
+		assert_eq!(it, expected);
 	}
 	// endregion
 
@@ -140,8 +331,15 @@ mod tests {
 		let l: LinkedList<u64> = LinkedList::from_iter(vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 0]);
 		let it: Increasing<std::collections::linked_list::IntoIter<u64>> =
 			Increasing::new(l.clone());
-
-		assert_eq!(it, Increasing { inner: l.into_iter(), previous: None });
+		let expected: Increasing<std::collections::linked_list::IntoIter<u64>> = Increasing {
+			inner: l.into_iter(),
+			previous: None,
+			key: Clone::clone,
+			mode: Monotonic::Increasing,
+			is_strict: true,
+		};
+
+		assert_eq!(it, expected);
 	}
 	// endregion
 
@@ -285,4 +483,251 @@ mod tests {
 		assert_eq!(it.next(), None);
 	}
 	// endregion
+
+	// region: by_00
+	#[test]
+	fn by_00() {
+		let v: Vec<(char, u8)> = vec![('a', 3), ('b', 1), ('c', 5), ('d', 5), ('e', 4), ('f', 8)];
+		let mut it = Increasing::by(v, |&(_, n): &(char, u8)| n);
+
+		assert_eq!(it.next(), Some(('a', 3)));
+		assert_eq!(it.next(), Some(('c', 5)));
+		assert_eq!(it.next(), Some(('f', 8)));
+		assert_eq!(it.next(), None);
+		assert_eq!(it.next(), None);
+	}
+	// endregion
+
+	// region: by_01
+	#[test]
+	fn by_01() {
+		let v: Vec<String> = vec![
+			"b".to_string(),
+			"aa".to_string(),
+			"ccc".to_string(),
+			"dd".to_string(),
+			"eeee".to_string(),
+		];
+		let mut it = Increasing::by(v, String::len);
+
+		assert_eq!(it.next(), Some("b".to_string()));
+		assert_eq!(it.next(), Some("aa".to_string()));
+		assert_eq!(it.next(), Some("ccc".to_string()));
+		assert_eq!(it.next(), Some("eeee".to_string()));
+		assert_eq!(it.next(), None);
+	}
+	// endregion
+
+	// region: by_02
+	#[test]
+	fn by_02() {
+		let v: Vec<(u8, f32)> = vec![(0, 1.0), (1, f32::NAN), (2, 2.0), (3, 3.0)];
+		let mut it = Increasing::by(v, |&(_, x): &(u8, f32)| x);
+
+		assert_eq!(it.next(), Some((0, 1.0)));
+		assert_eq!(it.next(), Some((2, 2.0)));
+		assert_eq!(it.next(), Some((3, 3.0)));
+		assert_eq!(it.next(), None);
+	}
+	// endregion
+
+	// region: with_mode_00
+	#[test]
+	fn with_mode_00() {
+		let a: [u8; 4] = [9, 8, 7, 6];
+		let mut it: Increasing<std::array::IntoIter<u8, 4>> =
+			Increasing::new(a).with_mode(Monotonic::Decreasing);
+
+		assert_eq!(it.next(), Some(9));
+		assert_eq!(it.next(), Some(8));
+		assert_eq!(it.next(), Some(7));
+		assert_eq!(it.next(), Some(6));
+		assert_eq!(it.next(), None);
+		assert_eq!(it.next(), None);
+	}
+	// endregion
+
+	// region: with_mode_01
+	#[test]
+	fn with_mode_01() {
+		let v: Vec<u32> = vec![0, 1, 2, 3, 4, 3, 2, 1, 0];
+		let mut it: Increasing<std::vec::IntoIter<u32>> =
+			Increasing::new(v).with_mode(Monotonic::Decreasing);
+
+		assert_eq!(it.next(), Some(0));
+		assert_eq!(it.next(), None);
+	}
+	// endregion
+
+	// region: with_mode_02
+	#[test]
+	fn with_mode_02() {
+		let v: Vec<f64> = vec![f64::INFINITY, 21.21, 21.21, f64::NAN, -42.42, f64::NAN];
+		let mut it: Increasing<std::vec::IntoIter<f64>> =
+			Increasing::new(v).with_mode(Monotonic::Decreasing);
+
+		assert_eq!(it.next(), Some(f64::INFINITY));
+		assert_eq!(it.next(), Some(21.21));
+		assert_eq!(it.next(), Some(-42.42));
+		assert_eq!(it.next(), None);
+	}
+	// endregion
+
+	// region: with_mode_03
+	#[test]
+	fn with_mode_03() {
+		let v: Vec<f64> = vec![f64::NAN, f64::INFINITY, 0.0, f64::NEG_INFINITY];
+		let mut it: Increasing<std::vec::IntoIter<f64>> =
+			Increasing::new(v).with_mode(Monotonic::Decreasing);
+
+		assert!(it.next().unwrap().is_nan());
+		assert_eq!(it.next(), None);
+	}
+	// endregion
+
+	// region: with_mode_04
+	#[test]
+	fn with_mode_04() {
+		let v: Vec<(char, u8)> = vec![('a', 3), ('b', 1), ('c', 5), ('d', 1), ('e', 0)];
+		let mut it = Increasing::by(v, |&(_, n): &(char, u8)| n).with_mode(Monotonic::Decreasing);
+
+		assert_eq!(it.next(), Some(('a', 3)));
+		assert_eq!(it.next(), Some(('b', 1)));
+		assert_eq!(it.next(), Some(('e', 0)));
+		assert_eq!(it.next(), None);
+	}
+	// endregion
+
+	// region: non_strict_00
+	#[test]
+	fn non_strict_00() {
+		let v: Vec<u32> = vec![1, 2, 2, 3];
+		let it: Increasing<std::vec::IntoIter<u32>> = Increasing::non_strict(v.clone());
+		let expected: Increasing<std::vec::IntoIter<u32>> = Increasing {
+			inner: v.into_iter(),
+			previous: None,
+			key: Clone::clone,
+			mode: Monotonic::Increasing,
+			is_strict: false,
+		};
+
+		assert_eq!(it, expected);
+	}
+	// endregion
+
+	// region: non_strict_01
+	#[test]
+	fn non_strict_01() {
+		let a: [u8; 4] = [1, 2, 2, 3];
+		let strict: Vec<u8> = Increasing::new(a).collect();
+		let non_strict: Vec<u8> = Increasing::non_strict(a).collect();
+
+		assert_eq!(strict, [1, 2, 3]);
+		assert_eq!(non_strict, [1, 2, 2, 3]);
+	}
+	// endregion
+
+	// region: non_strict_02
+	#[test]
+	fn non_strict_02() {
+		let a: [u64; 13] = [0, 1, 0, 1, 1, 0, 1, 2, 3, 3, 2, 3, 4];
+		let strict: Vec<u64> = Increasing::new(a).collect();
+		let non_strict: Vec<u64> = Increasing::non_strict(a).collect();
+
+		assert_eq!(strict, [0, 1, 2, 3, 4]);
+		assert_eq!(non_strict, [0, 1, 1, 1, 1, 2, 3, 3, 3, 4]);
+	}
+	// endregion
+
+	// region: non_strict_03
+	#[test]
+	fn non_strict_03() {
+		let a: [u16; 5] = [5, 5, 4, 3, 3];
+		let strict: Vec<u16> = Increasing::new(a).with_mode(Monotonic::Decreasing).collect();
+		let non_strict: Vec<u16> =
+			Increasing::non_strict(a).with_mode(Monotonic::Decreasing).collect();
+
+		assert_eq!(strict, [5, 4, 3]);
+		assert_eq!(non_strict, [5, 5, 4, 3, 3]);
+	}
+	// endregion
+
+	// region: non_strict_04
+	#[test]
+	fn non_strict_04() {
+		let v: Vec<f32> = vec![1.0, 1.0, f32::NAN, 2.0, 2.0];
+		let strict: Vec<f32> = Increasing::new(v.clone()).collect();
+		let non_strict: Vec<f32> = Increasing::non_strict(v).collect();
+
+		assert_eq!(strict, [1.0, 2.0]);
+		assert_eq!(non_strict, [1.0, 1.0, 2.0, 2.0]);
+	}
+	// endregion
+
+	// region: size_hint_00
+	#[test]
+	fn size_hint_00() {
+		let a: [u8; 0] = [];
+		let it: Increasing<std::array::IntoIter<u8, 0>> = Increasing::new(a);
+
+		assert_eq!(it.size_hint(), (0, Some(0)));
+	}
+	// endregion
+
+	// region: size_hint_01
+	#[test]
+	fn size_hint_01() {
+		let v: Vec<u32> = vec![0, 1, 2, 3, 4, 3, 2, 1, 0];
+		let mut it: Increasing<std::vec::IntoIter<u32>> = Increasing::new(v);
+
+		loop {
+			let (lower, upper): (usize, Option<usize>) = it.size_hint();
+
+			assert_eq!(lower, 0);
+			assert_eq!(upper, Some(it.inner.len()));
+			if it.next().is_none() {
+				break;
+			}
+		}
+		assert_eq!(it.size_hint(), (0, Some(0)));
+	}
+	// endregion
+
+	// region: size_hint_02
+	#[test]
+	fn size_hint_02() {
+		let it = Increasing::by((0..).map(|n: u64| n % 7), |&n: &u64| n);
+
+		assert_eq!(it.size_hint(), (0, None));
+	}
+	// endregion
+
+	// region: fused_00
+	#[test]
+	fn fused_00() {
+		let v: Vec<u64> = vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 0];
+		let mut it: Increasing<std::vec::IntoIter<u64>> = Increasing::new(v);
+
+		assert_eq!(it.next(), Some(9));
+		for _ in 0..16 {
+			assert_eq!(it.next(), None);
+		}
+	}
+	// endregion
+
+	// region: fused_01
+	#[test]
+	fn fused_01() {
+		fn assert_fused<I: FusedIterator>(_: &I) {}
+
+		let a: [f64; 3] = [f64::NAN, 0.0, 1.0];
+		let mut it: Increasing<std::array::IntoIter<f64, 3>> = Increasing::new(a);
+
+		assert_fused(&it);
+		assert!(it.next().unwrap().is_nan());
+		for _ in 0..16 {
+			assert_eq!(it.next(), None);
+		}
+	}
+	// endregion
 }
@@ -13,6 +13,22 @@ const fn min(a: Integer, b: Integer) -> Integer {
 	}
 }
 
+#[inline(always)]
+const fn gcd(mut a: Integer, mut b: Integer) -> Integer {
+	while b != 0 {
+		(a, b) = (b, a % b);
+	}
+	a
+}
+
+#[inline(always)]
+fn lcm(a: Integer, b: Integer) -> Integer {
+	if a == 0 || b == 0 {
+		return 0;
+	}
+	(a / gcd(a, b)).checked_mul(b).expect("Least common multiple does not fit in an Integer")
+}
+
 type BitField = usize;
 
 /// An implementation of the Sieve of Eratosthenes.
@@ -58,23 +74,91 @@ impl Sieve {
 	/// ```
 	#[inline(always)]
 	pub fn new() -> Self {
-		const FIRST: Integer = match STARTING_PRIMES.last() {
+		Self::from_primes(STARTING_PRIMES.to_vec())
+	}
+
+	/// Creates a new Sieve instance, seeded with a known prefix of the prime numbers.
+	/// The newly created Sieve instance is used to find the prime numbers
+	/// that are greater than the last seed, up to whatever limit we want.
+	///
+	/// ### Parameters
+	/// * `primes` - The first prime numbers, sorted in ascending order.
+	///
+	/// ### Return
+	/// The newly created Sieve instance.
+	///
+	/// ### Panic
+	/// `primes` is not made of the first prime numbers, sorted in ascending order.
+	///
+	/// ### Example
+	/// ```
+	/// use ex04::Sieve;
+	///
+	/// let sieve: Sieve = Sieve::with_starting_primes(&[2, 3, 5, 7]);
+	/// ```
+	pub fn with_starting_primes(primes: &[Integer]) -> Self {
+		let mut reference: Self = Self::new();
+
+		for prime in primes {
+			assert!(reference.find_next_prime() == Some(*prime), "Invalid starting primes");
+		}
+
+		Self::from_primes(primes.to_vec())
+	}
+
+	/// Gets the n-th prime number, starting from 0.
+	/// The prime numbers found so far are reused,
+	/// and the sieve is only run further when `n` has not been reached yet.
+	///
+	/// ### Parameters
+	/// * `n` - The index of the prime number to get.
+	///
+	/// ### Return
+	/// * `Some(prime)` - The n-th prime number.
+	/// * `None` - The n-th prime number does not fit in an `Integer`.
+	///
+	/// ### Example
+	/// ```
+	/// use ex04::Sieve;
+	///
+	/// let mut sieve: Sieve = Sieve::new();
+	///
+	/// assert_eq!(sieve.nth_prime(0), Some(2));
+	/// assert_eq!(sieve.nth_prime(10), Some(31));
+	/// assert_eq!(sieve.nth_prime(4), Some(11));
+	/// ```
+	pub fn nth_prime(self: &mut Self, n: usize) -> Option<Integer> {
+		while self.primes_found_so_far.len() <= n {
+			self.find_next_prime()?;
+		}
+
+		Some(self.primes_found_so_far[n])
+	}
+
+	/// Creates a new Sieve instance, seeded with a known prefix of the prime numbers,
+	/// without checking it.
+	///
+	/// ### Parameters
+	/// * `primes` - The first prime numbers, sorted in ascending order.
+	///
+	/// ### Return
+	/// The newly created Sieve instance.
+	fn from_primes(primes: Vec<Integer>) -> Self {
+		let first: Integer = match primes.last() {
 			Some(last) if *last < Integer::MAX => *last + 1,
 			None => 2,
 			_ => 0,
 		};
-		const REMAINING_NUMBERS: Integer = match FIRST {
+		let remaining_numbers: Integer = match first {
 			0 => 0,
-			_ => Integer::MAX - FIRST + 1,
+			_ => Integer::MAX - first + 1,
 		};
-		const LEN: Integer = min(BitField::BITS as Integer, REMAINING_NUMBERS);
-
 		let mut sieve: Self = Self {
-			primes_found_so_far: STARTING_PRIMES.to_vec(),
+			primes_found_so_far: primes,
 			range: !0,
-			first: FIRST,
-			remaining_numbers: REMAINING_NUMBERS,
-			len: LEN,
+			first,
+			remaining_numbers,
+			len: min(BitField::BITS as Integer, remaining_numbers),
 		};
 
 		sieve.remove_non_primes();
@@ -246,6 +330,27 @@ impl Prime {
 	pub fn new(n: Integer) -> Self {
 		Self { n, sieve: Sieve::new(), is_end_reached: false }
 	}
+
+	/// Creates a new iterator that generates the prime numbers of a half-open range.
+	///
+	/// ### Parameters
+	/// * `lo` - The first number of the range, included.
+	/// * `hi` - The last number of the range, excluded.
+	///
+	/// ### Return
+	/// An iterator that generates every prime number `p` such that `lo <= p < hi`,
+	/// in ascending order.
+	///
+	/// ### Example
+	/// ```
+	/// use ex04::Prime;
+	///
+	/// assert_eq!(Prime::primes_in_range(10, 20).collect::<Vec<_>>(), [11, 13, 17, 19]);
+	/// ```
+	pub fn primes_in_range(lo: Integer, hi: Integer) -> impl Iterator<Item = Integer> {
+		Self { n: lo, sieve: Sieve::new(), is_end_reached: lo >= hi }
+			.take_while(move |prime: &Integer| *prime < hi)
+	}
 }
 // endregion
 
@@ -316,6 +421,71 @@ impl Iterator for Prime {
 }
 // endregion
 
+/// An iterator that generates twin prime numbers,
+/// that is pairs of prime numbers that differ by 2.
+pub struct TwinPrimes {
+	/// The iterator that is used to generate the prime numbers.
+	primes: Prime,
+
+	/// The last prime number generated by `self.primes`, if any.
+	previous: Option<Integer>,
+}
+
+// region: impl TwinPrimes
+impl TwinPrimes {
+	/// Creates a new TwinPrimes iterator instance and initializes its attributes.
+	/// The newly created TwinPrimes iterator instance is used to get the twin prime numbers,
+	/// generating the next pair at each iteration.
+	///
+	/// ### Return
+	/// The newly created TwinPrimes iterator instance.
+	///
+	/// ### Example
+	/// ```
+	/// use ex04::TwinPrimes;
+	///
+	/// let mut twin_primes: TwinPrimes = TwinPrimes::new();
+	/// ```
+	pub fn new() -> Self {
+		Self { primes: Prime::new(2), previous: None }
+	}
+}
+// endregion
+
+// region: impl Iterator for TwinPrimes
+impl Iterator for TwinPrimes {
+	type Item = (Integer, Integer);
+
+	/// Generates the next pair of twin prime numbers.
+	///
+	/// ### Return
+	/// * `Some((p, p + 2))` - The next pair of twin prime numbers.
+	/// * `None` - There is no next pair of twin prime numbers that fits in an `Integer`.
+	///
+	/// ### Example
+	/// ```
+	/// use ex04::TwinPrimes;
+	///
+	/// let mut twin_primes: TwinPrimes = TwinPrimes::new();
+	///
+	/// assert_eq!(twin_primes.next(), Some((3, 5)));
+	/// assert_eq!(twin_primes.next(), Some((5, 7)));
+	/// assert_eq!(twin_primes.next(), Some((11, 13)));
+	/// ```
+	fn next(self: &mut Self) -> Option<Self::Item> {
+		loop {
+			let prime: Integer = self.primes.next()?;
+
+			if let Some(previous) = self.previous.replace(prime) {
+				if prime - previous == 2 {
+					return Some((previous, prime));
+				}
+			}
+		}
+	}
+}
+// endregion
+
 type Exponent = u8;
 type PrimeFactor = (Integer, Exponent);
 
@@ -361,6 +531,91 @@ pub fn prime_decomposition(mut n: Integer) -> Vec<PrimeFactor> {
 	prime_factors
 }
 
+/// Sums the first `n` prime numbers.
+/// If there are less than `n` prime numbers that fit in an `Integer`,
+/// only those are summed.
+///
+/// ### Parameters
+/// * `n` - The number of prime numbers to sum.
+///
+/// ### Return
+/// The sum of the first `n` prime numbers.
+///
+/// ### Example
+/// ```
+/// use ex04::sum_first_n_primes;
+///
+/// assert_eq!(sum_first_n_primes(0), 0);
+/// assert_eq!(sum_first_n_primes(3), 10);
+/// ```
+pub fn sum_first_n_primes(n: usize) -> u64 {
+	Prime::new(2).take(n).map(u64::from).sum()
+}
+
+/// Computes the greatest common divisor of a set of numbers.
+///
+/// ### Parameters
+/// * `xs` - The numbers to compute the greatest common divisor of.
+///
+/// ### Return
+/// The greatest common divisor of the numbers in `xs`, or 0 if `xs` is empty.
+///
+/// ### Example
+/// ```
+/// use ex04::gcd_all;
+///
+/// assert_eq!(gcd_all(&[]), 0);
+/// assert_eq!(gcd_all(&[12, 18, 24]), 6);
+/// ```
+pub fn gcd_all(xs: &[Integer]) -> Integer {
+	xs.iter().fold(0, |acc: Integer, &x: &Integer| gcd(acc, x))
+}
+
+/// Computes the least common multiple of a set of numbers.
+///
+/// ### Parameters
+/// * `xs` - The numbers to compute the least common multiple of.
+///
+/// ### Return
+/// The least common multiple of the numbers in `xs`, or 1 if `xs` is empty.
+///
+/// ### Panic
+/// The least common multiple does not fit in an `Integer`.
+///
+/// ### Example
+/// ```
+/// use ex04::lcm_all;
+///
+/// assert_eq!(lcm_all(&[]), 1);
+/// assert_eq!(lcm_all(&[4, 6]), 12);
+/// ```
+pub fn lcm_all(xs: &[Integer]) -> Integer {
+	xs.iter().fold(1, |acc: Integer, &x: &Integer| lcm(acc, x))
+}
+
+/// Decompose every number of a range into its prime factors, with for each, its exponent.
+///
+/// ### Parameters
+/// * `low` - The first number of the range.
+/// * `high` - The last number of the range, included.
+///
+/// ### Return
+/// A vector that contains, for each number from `low` to `high`, the number itself
+/// and its prime factors as returned by `prime_decomposition`.
+///
+/// ### Example
+/// ```
+/// use ex04::factorizations_in_range;
+///
+/// assert_eq!(
+/// 	factorizations_in_range(2, 4),
+/// 	vec![(2, vec![(2, 1)]), (3, vec![(3, 1)]), (4, vec![(2, 2)])]
+/// );
+/// ```
+pub fn factorizations_in_range(low: Integer, high: Integer) -> Vec<(Integer, Vec<PrimeFactor>)> {
+	(low..=high).map(|n: Integer| (n, prime_decomposition(n))).collect()
+}
+
 #[cfg(test)]
 mod tests {
 	use primes::PrimeSet;
@@ -412,6 +667,64 @@ mod tests {
 	}
 	// endregion
 
+	// region: sieve_with_starting_primes_00
+	#[test]
+	fn sieve_with_starting_primes_00() {
+		let mut sieve: Sieve = Sieve::with_starting_primes(&[2, 3, 5, 7]);
+
+		assert_eq!(sieve.primes_found_so_far, [2, 3, 5, 7]);
+		assert_eq!(sieve.first, 8);
+		assert_eq!(sieve.find_next_prime(), Some(11));
+		assert_eq!(sieve.find_next_prime(), Some(13));
+	}
+	// endregion
+
+	// region: sieve_with_starting_primes_01
+	#[test]
+	fn sieve_with_starting_primes_01() {
+		let mut sieve: Sieve = Sieve::with_starting_primes(&[]);
+
+		for prime in PRIMES {
+			assert_eq!(sieve.find_next_prime(), Some(prime));
+		}
+	}
+	// endregion
+
+	// region: sieve_with_starting_primes_02
+	#[test]
+	fn sieve_with_starting_primes_02() {
+		let mut sieve: Sieve = Sieve::with_starting_primes(&PRIMES[..42]);
+
+		for prime in &PRIMES[42..] {
+			assert_eq!(sieve.find_next_prime(), Some(*prime));
+		}
+	}
+	// endregion
+
+	// region: sieve_with_starting_primes_03
+	#[test]
+	#[should_panic(expected = "Invalid starting primes")]
+	fn sieve_with_starting_primes_03() {
+		Sieve::with_starting_primes(&[2, 3, 4]);
+	}
+	// endregion
+
+	// region: sieve_with_starting_primes_04
+	#[test]
+	#[should_panic(expected = "Invalid starting primes")]
+	fn sieve_with_starting_primes_04() {
+		Sieve::with_starting_primes(&[3, 2]);
+	}
+	// endregion
+
+	// region: sieve_with_starting_primes_05
+	#[test]
+	#[should_panic(expected = "Invalid starting primes")]
+	fn sieve_with_starting_primes_05() {
+		Sieve::with_starting_primes(&[2, 3, 7]);
+	}
+	// endregion
+
 	// region: sieve_fill_with_next_chunk_00
 	#[test]
 	fn sieve_fill_with_next_chunk_00() {
@@ -1481,6 +1794,85 @@ mod tests {
 	}
 	// endregion
 
+	// region: sieve_nth_prime_00
+	#[test]
+	fn sieve_nth_prime_00() {
+		let mut sieve: Sieve = Sieve::new();
+
+		assert_eq!(sieve.nth_prime(0), Some(2));
+		assert_eq!(sieve.primes_found_so_far, [2]);
+	}
+	// endregion
+
+	// region: sieve_nth_prime_01
+	#[test]
+	fn sieve_nth_prime_01() {
+		let mut sieve: Sieve = Sieve::new();
+
+		assert_eq!(sieve.nth_prime(10), Some(31));
+		assert_eq!(sieve.primes_found_so_far, PRIMES[..11]);
+	}
+	// endregion
+
+	// region: sieve_nth_prime_02
+	#[test]
+	fn sieve_nth_prime_02() {
+		let mut sieve: Sieve = Sieve::new();
+
+		assert_eq!(sieve.nth_prime(10), Some(31));
+		assert_eq!(sieve.nth_prime(3), Some(7));
+		assert_eq!(sieve.nth_prime(0), Some(2));
+		assert_eq!(sieve.primes_found_so_far.len(), 11);
+	}
+	// endregion
+
+	// region: sieve_nth_prime_03
+	#[test]
+	fn sieve_nth_prime_03() {
+		let mut sieve: Sieve = Sieve::new();
+
+		for (n, prime) in PRIMES.iter().enumerate() {
+			assert_eq!(sieve.nth_prime(n), Some(*prime));
+		}
+	}
+	// endregion
+
+	// region: sieve_nth_prime_04
+	#[test]
+	fn sieve_nth_prime_04() {
+		let mut sieve: Sieve = Sieve::with_starting_primes(&[2, 3, 5, 7]);
+
+		assert_eq!(sieve.nth_prime(2), Some(5));
+		assert_eq!(sieve.nth_prime(4), Some(11));
+	}
+	// endregion
+
+	// region: sieve_nth_prime_05
+	#[test]
+	fn sieve_nth_prime_05() {
+		let primes: Vec<Integer> = {
+			// region: primes
+			let mut v: Vec<Integer> = Vec::new();
+
+			for prime in primes::Sieve::new().iter() {
+				if prime > Integer::MAX as u64 {
+					break;
+				}
+				v.push(prime as Integer);
+			}
+
+			v
+			// endregion
+		};
+		let mut sieve: Sieve = Sieve::new();
+
+		assert_eq!(sieve.nth_prime(primes.len() - 1), primes.last().copied());
+		assert_eq!(sieve.nth_prime(primes.len()), None);
+		assert_eq!(sieve.nth_prime(usize::MAX), None);
+		assert_eq!(sieve.primes_found_so_far, primes);
+	}
+	// endregion
+
 	// region: lower_bound_00
 	#[test]
 	fn lower_bound_00() {
@@ -1792,6 +2184,108 @@ mod tests {
 	}
 	// endregion
 
+	// region: prime_primes_in_range_00
+	#[test]
+	fn prime_primes_in_range_00() {
+		assert_eq!(Prime::primes_in_range(10, 20).collect::<Vec<Integer>>(), [11, 13, 17, 19]);
+	}
+	// endregion
+
+	// region: prime_primes_in_range_01
+	#[test]
+	fn prime_primes_in_range_01() {
+		assert_eq!(Prime::primes_in_range(11, 19).collect::<Vec<Integer>>(), [11, 13, 17]);
+	}
+	// endregion
+
+	// region: prime_primes_in_range_02
+	#[test]
+	fn prime_primes_in_range_02() {
+		assert_eq!(Prime::primes_in_range(0, 0).next(), None);
+		assert_eq!(Prime::primes_in_range(42, 21).next(), None);
+		assert_eq!(Prime::primes_in_range(2, 2).next(), None);
+		assert_eq!(Prime::primes_in_range(24, 29).next(), None);
+	}
+	// endregion
+
+	// region: prime_primes_in_range_03
+	#[test]
+	fn prime_primes_in_range_03() {
+		assert_eq!(Prime::primes_in_range(0, 3).collect::<Vec<Integer>>(), [2]);
+		assert_eq!(Prime::primes_in_range(0, 256).collect::<Vec<Integer>>(), PRIMES);
+	}
+	// endregion
+
+	// region: prime_primes_in_range_04
+	#[test]
+	fn prime_primes_in_range_04() {
+		const FIRST: Integer = Integer::MAX - 100;
+		let mut primes = Prime::primes_in_range(FIRST, Integer::MAX);
+
+		for n in FIRST..Integer::MAX {
+			if primes::is_prime(n as u64) {
+				assert_eq!(primes.next(), Some(n));
+			}
+		}
+		assert_eq!(primes.next(), None);
+	}
+	// endregion
+
+	// region: twin_primes_new_00
+	#[test]
+	fn twin_primes_new_00() {
+		let twin_primes: TwinPrimes = TwinPrimes::new();
+
+		assert_eq!(twin_primes.primes.n, 2);
+		assert_eq!(twin_primes.previous, None);
+	}
+	// endregion
+
+	// region: twin_primes_next_00
+	#[test]
+	fn twin_primes_next_00() {
+		let mut twin_primes: TwinPrimes = TwinPrimes::new();
+
+		assert_eq!(twin_primes.next(), Some((3, 5)));
+		assert_eq!(twin_primes.next(), Some((5, 7)));
+		assert_eq!(twin_primes.next(), Some((11, 13)));
+		assert_eq!(twin_primes.next(), Some((17, 19)));
+	}
+	// endregion
+
+	// region: twin_primes_next_01
+	#[test]
+	fn twin_primes_next_01() {
+		for (p, q) in TwinPrimes::new().take(42) {
+			assert_eq!(q - p, 2);
+			assert!(primes::is_prime(p as u64));
+			assert!(primes::is_prime(q as u64));
+		}
+	}
+	// endregion
+
+	// region: twin_primes_next_02
+	#[test]
+	fn twin_primes_next_02() {
+		let twin_primes: Vec<(Integer, Integer)> =
+			TwinPrimes::new().take_while(|(p, _): &(Integer, Integer)| *p < 256).collect();
+
+		for (p, q) in PRIMES.iter().zip(PRIMES.iter().skip(1)) {
+			assert_eq!(twin_primes.contains(&(*p, *q)), q - p == 2);
+		}
+	}
+	// endregion
+
+	// region: twin_primes_next_03
+	#[test]
+	fn twin_primes_next_03() {
+		let twin_primes: Vec<(Integer, Integer)> = TwinPrimes::new().collect();
+
+		assert_eq!(twin_primes.len(), 860);
+		assert_eq!(twin_primes.last(), Some(&(65519, 65521)));
+	}
+	// endregion
+
 	// region: prime_decomposition_00
 	#[test]
 	fn prime_decomposition_00() {
@@ -1875,4 +2369,167 @@ mod tests {
 		assert_eq!(prime_decomposition(128), vec![(2, 7)]);
 	}
 	// endregion
+
+	// region: sum_first_n_primes_00
+	#[test]
+	fn sum_first_n_primes_00() {
+		assert_eq!(sum_first_n_primes(0), 0);
+	}
+	// endregion
+
+	// region: sum_first_n_primes_01
+	#[test]
+	fn sum_first_n_primes_01() {
+		assert_eq!(sum_first_n_primes(1), 2);
+	}
+	// endregion
+
+	// region: sum_first_n_primes_02
+	#[test]
+	fn sum_first_n_primes_02() {
+		assert_eq!(sum_first_n_primes(3), 10);
+	}
+	// endregion
+
+	// region: sum_first_n_primes_03
+	#[test]
+	fn sum_first_n_primes_03() {
+		assert_eq!(
+			sum_first_n_primes(PRIMES.len()),
+			PRIMES.iter().map(|&prime| prime as u64).sum::<u64>()
+		);
+	}
+	// endregion
+
+	// region: sum_first_n_primes_04
+	#[test]
+	fn sum_first_n_primes_04() {
+		let expected: u64 = (2..=Integer::MAX as u64).filter(|&n| primes::is_prime(n)).sum();
+
+		assert_eq!(sum_first_n_primes(usize::MAX), expected);
+	}
+	// endregion
+
+	// region: gcd_all_00
+	#[test]
+	fn gcd_all_00() {
+		assert_eq!(gcd_all(&[]), 0);
+	}
+	// endregion
+
+	// region: gcd_all_01
+	#[test]
+	fn gcd_all_01() {
+		assert_eq!(gcd_all(&[42]), 42);
+	}
+	// endregion
+
+	// region: gcd_all_02
+	#[test]
+	fn gcd_all_02() {
+		assert_eq!(gcd_all(&[12, 18, 24]), 6);
+	}
+	// endregion
+
+	// region: gcd_all_03
+	#[test]
+	fn gcd_all_03() {
+		assert_eq!(gcd_all(&[0, 12, 0, 18]), 6);
+	}
+	// endregion
+
+	// region: gcd_all_04
+	#[test]
+	fn gcd_all_04() {
+		assert_eq!(gcd_all(&[17, 31]), 1);
+	}
+	// endregion
+
+	// region: lcm_all_00
+	#[test]
+	fn lcm_all_00() {
+		assert_eq!(lcm_all(&[]), 1);
+	}
+	// endregion
+
+	// region: lcm_all_01
+	#[test]
+	fn lcm_all_01() {
+		assert_eq!(lcm_all(&[42]), 42);
+	}
+	// endregion
+
+	// region: lcm_all_02
+	#[test]
+	fn lcm_all_02() {
+		assert_eq!(lcm_all(&[4, 6]), 12);
+	}
+	// endregion
+
+	// region: lcm_all_03
+	#[test]
+	fn lcm_all_03() {
+		assert_eq!(lcm_all(&[2, 3, 4, 5, 6]), 60);
+	}
+	// endregion
+
+	// region: lcm_all_04
+	#[test]
+	fn lcm_all_04() {
+		assert_eq!(lcm_all(&[4, 0, 6]), 0);
+	}
+	// endregion
+
+	// region: lcm_all_05
+	#[test]
+	#[should_panic(expected = "Least common multiple does not fit in an Integer")]
+	fn lcm_all_05() {
+		lcm_all(&[251, 241, 239]);
+	}
+	// endregion
+
+	// region: factorizations_in_range_00
+	#[test]
+	fn factorizations_in_range_00() {
+		assert_eq!(factorizations_in_range(4, 2), vec![]);
+	}
+	// endregion
+
+	// region: factorizations_in_range_01
+	#[test]
+	fn factorizations_in_range_01() {
+		assert_eq!(
+			factorizations_in_range(2, 4),
+			vec![(2, vec![(2, 1)]), (3, vec![(3, 1)]), (4, vec![(2, 2)])]
+		);
+	}
+	// endregion
+
+	// region: factorizations_in_range_02
+	#[test]
+	fn factorizations_in_range_02() {
+		assert_eq!(factorizations_in_range(0, 1), vec![(0, vec![]), (1, vec![])]);
+	}
+	// endregion
+
+	// region: factorizations_in_range_03
+	#[test]
+	fn factorizations_in_range_03() {
+		assert_eq!(factorizations_in_range(72, 72), vec![(72, vec![(2, 3), (3, 2)])]);
+	}
+	// endregion
+
+	// region: factorizations_in_range_04
+	#[test]
+	fn factorizations_in_range_04() {
+		for (n, prime_factors) in factorizations_in_range(Integer::MAX - 16, Integer::MAX) {
+			let product: u64 = prime_factors
+				.iter()
+				.map(|&(prime, exponent)| (prime as u64).pow(exponent as u32))
+				.product();
+
+			assert_eq!(product, n as u64);
+		}
+	}
+	// endregion
 }
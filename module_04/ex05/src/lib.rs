@@ -1,6 +1,9 @@
+use std::collections::HashSet;
+
 pub struct Groups<'a, F> {
 	s: &'a str,
 	f: F,
+	consumed: usize,
 }
 
 impl<'a, F> Groups<'a, F> {
@@ -24,7 +27,77 @@ impl<'a, F> Groups<'a, F> {
 	where
 		F: FnMut(char) -> bool,
 	{
-		Groups { s, f }
+		Groups { s, f, consumed: 0 }
+	}
+
+	/// Turns the calling Groups instance into an iterator that also yields
+	/// the position of each group in the original string.
+	///
+	/// ### Return
+	/// An iterator that yields each group of characters that satisfy the predicate,
+	/// together with its starting byte offset in the original string.
+	///
+	/// ### Examples
+	/// ```
+	/// use ex05::Groups;
+	///
+	/// type F = fn(char) -> bool;
+	///
+	/// let groups: Groups<'_, F> = Groups::new("Hello Rust!", |c| c.is_alphabetic());
+	///
+	/// assert_eq!(groups.indexed().collect::<Vec<_>>(), [(0, "Hello"), (6, "Rust")]);
+	/// ```
+	pub fn indexed(mut self) -> impl Iterator<Item = (usize, &'a str)>
+	where
+		F: FnMut(char) -> bool,
+	{
+		std::iter::from_fn(move || {
+			let group: &'a str = self.next()?;
+
+			Some((self.consumed - group.len(), group))
+		})
+	}
+
+	/// Searches for the next group of characters that satisfy a predicate,
+	/// and moves the remaining string past it.
+	///
+	/// ### Type parameters
+	/// * `P` - The type of the predicate.
+	///
+	/// ### Parameters
+	/// * `s` - The remaining string to search in.
+	/// * `consumed` - The number of bytes of the original string that have been consumed.
+	/// * `p` - The predicate that the characters of a group must satisfy.
+	///
+	/// ### Return
+	/// * `Some(group)` - The next group of characters that satisfy the predicate.
+	/// * `None` - There are no more groups of characters that satisfy the predicate.
+	fn next_where<P: FnMut(char) -> bool>(
+		s: &mut &'a str,
+		consumed: &mut usize,
+		mut p: P,
+	) -> Option<&'a str> {
+		match s.char_indices().find(|(_, c)| p(*c)) {
+			Some((i0, _)) => {
+				*s = &s[i0..];
+				*consumed += i0;
+				match s.char_indices().find(|(_, c)| !p(*c)) {
+					Some((i1, _)) => {
+						let (group, rest) = s.split_at(i1);
+						*s = rest;
+						*consumed += i1;
+						Some(group)
+					}
+					None => {
+						let group = *s;
+						*s = "";
+						*consumed += group.len();
+						Some(group)
+					}
+				}
+			}
+			None => None,
+		}
 	}
 }
 
@@ -53,27 +126,115 @@ where
 	/// assert_eq!(groups.next(), None);
 	/// ```
 	fn next(&mut self) -> Option<Self::Item> {
-		match self.s.char_indices().find(|(_, c)| (self.f)(*c)) {
-			Some((i0, _)) => {
-				self.s = &self.s[i0..];
-				match self.s.char_indices().find(|(_, c)| !(self.f)(*c)) {
-					Some((i1, _)) => {
-						let (group, rest) = self.s.split_at(i1);
-						self.s = rest;
-						Some(group)
-					}
-					None => {
-						let group = self.s;
-						self.s = "";
-						Some(group)
-					}
-				}
-			}
-			None => None,
-		}
+		Self::next_where(&mut self.s, &mut self.consumed, &mut self.f)
+	}
+}
+
+pub struct Gaps<'a, F> {
+	groups: Groups<'a, F>,
+}
+
+impl<'a, F> Gaps<'a, F> {
+	/// Creates a new Gaps instance and initializes its attributes.
+	///
+	/// ### Parameters
+	/// * `s` - The string to iterate over.
+	/// * `f` - The predicate that the characters of a gap must not satisfy.
+	///
+	/// ### Return
+	/// The newly created Gaps instance.
+	///
+	/// ### Examples
+	/// ```
+	/// use ex05::Gaps;
+	///
+	/// type F = fn(char) -> bool;
+	///
+	/// let gaps: Gaps<'_, F> = Gaps::new("Hello Rust!", |c| c.is_alphabetic());
+	/// ```
+	pub fn new(s: &'a str, f: F) -> Self
+	where
+		F: FnMut(char) -> bool,
+	{
+		Gaps { groups: Groups::new(s, f) }
 	}
 }
 
+impl<'a, F> Iterator for Gaps<'a, F>
+where
+	F: FnMut(char) -> bool,
+{
+	type Item = &'a str;
+
+	/// Searches for the next group of characters that do not satisfy the predicate.
+	///
+	/// ### Return
+	/// * `Some(gap)` - The next group of characters that do not satisfy the predicate.
+	/// * `None` - There are no more groups of characters that do not satisfy the predicate.
+	///
+	/// ### Examples
+	/// ```
+	/// use ex05::Gaps;
+	///
+	/// type F = fn(char) -> bool;
+	///
+	/// let mut gaps: Gaps<'_, F> = Gaps::new("Hello Rust!", |c| c.is_alphabetic());
+	///
+	/// assert_eq!(gaps.next(), Some(" "));
+	/// assert_eq!(gaps.next(), Some("!"));
+	/// assert_eq!(gaps.next(), None);
+	/// ```
+	fn next(&mut self) -> Option<Self::Item> {
+		let f: &mut F = &mut self.groups.f;
+
+		Groups::<F>::next_where(&mut self.groups.s, &mut self.groups.consumed, move |c: char| !f(c))
+	}
+}
+
+/// Collects the distinct words of a string,
+/// a word being a group of consecutive alphanumeric characters.
+///
+/// ### Parameters
+/// * `s` - The string to collect the words of.
+///
+/// ### Return
+/// The set of the distinct words of `s`.
+///
+/// ### Examples
+/// ```
+/// use std::collections::HashSet;
+///
+/// use ex05::unique_words;
+///
+/// assert_eq!(unique_words("a b a c"), HashSet::from(["a", "b", "c"]));
+/// ```
+pub fn unique_words(s: &str) -> HashSet<&str> {
+	Groups::new(s, |c: char| c.is_alphanumeric()).collect()
+}
+
+/// Splits a string into groups of consecutive characters that satisfy a predicate,
+/// and copies each of them into an owned string.
+///
+/// ### Type parameters
+/// * `F` - The type of the predicate.
+///
+/// ### Parameters
+/// * `s` - The string to split.
+/// * `f` - The predicate that the characters of a group must satisfy.
+///
+/// ### Return
+/// The groups of `s`, in order.
+///
+/// ### Examples
+/// ```
+/// use ex05::tokenize_owned;
+///
+/// assert_eq!(tokenize_owned("Hello Rust!", |c| c.is_alphabetic()), ["Hello", "Rust"]);
+/// ```
+pub fn tokenize_owned<F: FnMut(char) -> bool>(s: &str, f: F) -> Vec<String> {
+	Groups::new(s, f).map(String::from).collect()
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -895,4 +1056,229 @@ mod tests {
 		assert_eq!(groups.next(), None);
 	}
 	// endregion
+
+	// region: groups_indexed_00
+	#[test]
+	fn groups_indexed_00() {
+		let groups: Groups<'_, F> = Groups::new("", is_alphabetic);
+
+		assert_eq!(groups.indexed().next(), None);
+	}
+	// endregion
+
+	// region: groups_indexed_01
+	#[test]
+	fn groups_indexed_01() {
+		let groups: Groups<'_, F> = Groups::new("Hello Rust!", is_alphabetic);
+
+		assert_eq!(groups.indexed().collect::<Vec<(usize, &str)>>(), [(0, "Hello"), (6, "Rust")]);
+	}
+	// endregion
+
+	// region: groups_indexed_02
+	#[test]
+	fn groups_indexed_02() {
+		let groups: Groups<'_, F> = Groups::new("  ça  coûte  5¥", is_alphabetic);
+
+		assert_eq!(groups.indexed().collect::<Vec<(usize, &str)>>(), [(2, "ça"), (7, "coûte")]);
+	}
+	// endregion
+
+	// region: groups_indexed_03
+	#[test]
+	fn groups_indexed_03() {
+		const S: &str = "0nce upon a time, there existed 1 giant tree";
+
+		let groups: Groups<'_, F> = Groups::new(S, is_lowercase);
+
+		for (i, group) in groups.indexed() {
+			assert_eq!(&S[i..i + group.len()], group);
+		}
+	}
+	// endregion
+
+	// region: groups_indexed_04
+	#[test]
+	fn groups_indexed_04() {
+		let mut groups: Groups<'_, F> = Groups::new("abc 123 def", is_alphabetic);
+
+		assert_eq!(groups.next(), Some("abc"));
+		assert_eq!(groups.indexed().collect::<Vec<(usize, &str)>>(), [(8, "def")]);
+	}
+	// endregion
+
+	// region: gaps_new_00
+	#[test]
+	fn gaps_new_00() {
+		const S: &str = "Hello Rust!";
+
+		let gaps: Gaps<'_, F> = Gaps::new(S, is_whitespace);
+
+		assert_eq!(gaps.groups.s, S);
+		for c in CHARS {
+			assert_eq!((gaps.groups.f)(c), is_whitespace(c));
+		}
+	}
+	// endregion
+
+	// region: gaps_next_00
+	#[test]
+	fn gaps_next_00() {
+		let mut gaps: Gaps<'_, F> = Gaps::new("", is_alphabetic);
+
+		assert_eq!(gaps.next(), None);
+		assert_eq!(gaps.next(), None);
+	}
+	// endregion
+
+	// region: gaps_next_01
+	#[test]
+	fn gaps_next_01() {
+		let mut gaps: Gaps<'_, F> = Gaps::new("Hello Rust!", is_alphabetic);
+
+		assert_eq!(gaps.next(), Some(" "));
+		assert_eq!(gaps.next(), Some("!"));
+		assert_eq!(gaps.next(), None);
+	}
+	// endregion
+
+	// region: gaps_next_02
+	#[test]
+	fn gaps_next_02() {
+		let mut gaps: Gaps<'_, F> = Gaps::new("HelloRust", is_alphabetic);
+
+		assert_eq!(gaps.next(), None);
+		assert_eq!(gaps.next(), None);
+	}
+	// endregion
+
+	// region: gaps_next_03
+	#[test]
+	fn gaps_next_03() {
+		let mut gaps: Gaps<'_, F> = Gaps::new(" 42 ¥!\n", is_alphabetic);
+
+		assert_eq!(gaps.next(), Some(" 42 ¥!\n"));
+		assert_eq!(gaps.next(), None);
+	}
+	// endregion
+
+	// region: gaps_next_04
+	#[test]
+	fn gaps_next_04() {
+		let mut gaps: Gaps<'_, F> = Gaps::new("  To be, or not to be?  ", is_alphabetic);
+
+		assert_eq!(gaps.next(), Some("  "));
+		assert_eq!(gaps.next(), Some(" "));
+		assert_eq!(gaps.next(), Some(", "));
+		assert_eq!(gaps.next(), Some(" "));
+		assert_eq!(gaps.next(), Some(" "));
+		assert_eq!(gaps.next(), Some(" "));
+		assert_eq!(gaps.next(), Some("?  "));
+		assert_eq!(gaps.next(), None);
+	}
+	// endregion
+
+	// region: gaps_next_05
+	#[test]
+	fn gaps_next_05() {
+		const S: &str = "0nce upon a time, there existed 1 giant tree!";
+
+		let groups: Vec<&str> = Groups::new(S, is_lowercase).collect();
+		let gaps: Vec<&str> = Gaps::new(S, is_lowercase).collect();
+
+		assert_eq!(gaps.len(), groups.len() + 1);
+		assert_eq!(
+			gaps.iter().chain(groups.iter()).map(|s: &&str| s.len()).sum::<usize>(),
+			S.len()
+		);
+	}
+	// endregion
+
+	// region: unique_words_00
+	#[test]
+	fn unique_words_00() {
+		assert_eq!(unique_words(""), HashSet::new());
+	}
+	// endregion
+
+	// region: unique_words_01
+	#[test]
+	fn unique_words_01() {
+		let words: HashSet<&str> = unique_words("a b a c");
+
+		assert_eq!(words.len(), 3);
+		assert_eq!(words, HashSet::from(["a", "b", "c"]));
+	}
+	// endregion
+
+	// region: unique_words_02
+	#[test]
+	fn unique_words_02() {
+		assert_eq!(
+			unique_words("To be, or not to be: that is the question."),
+			HashSet::from(["To", "be", "or", "not", "to", "that", "is", "the", "question"])
+		);
+	}
+	// endregion
+
+	// region: unique_words_03
+	#[test]
+	fn unique_words_03() {
+		assert_eq!(unique_words(" \t\n!?.,;:"), HashSet::new());
+	}
+	// endregion
+
+	// region: unique_words_04
+	#[test]
+	fn unique_words_04() {
+		assert_eq!(
+			unique_words("The angels 👼🏽👼👼🏿 bore the Chosen 0ne, the 0ne"),
+			HashSet::from(["The", "angels", "bore", "the", "Chosen", "0ne"])
+		);
+	}
+	// endregion
+
+	// region: tokenize_owned_00
+	#[test]
+	fn tokenize_owned_00() {
+		assert_eq!(tokenize_owned("", is_alphabetic), Vec::<String>::new());
+	}
+	// endregion
+
+	// region: tokenize_owned_01
+	#[test]
+	fn tokenize_owned_01() {
+		assert_eq!(
+			tokenize_owned("Hello Rust", |c: char| c.is_alphabetic()),
+			vec!["Hello".to_string(), "Rust".to_string()]
+		);
+	}
+	// endregion
+
+	// region: tokenize_owned_02
+	#[test]
+	fn tokenize_owned_02() {
+		let tokens: Vec<String> = {
+			let s: String = String::from("0nce upon a time, there existed 1 giant tree");
+
+			tokenize_owned(&s, is_ascii_digit)
+		};
+
+		assert_eq!(tokens, ["0", "1"]);
+	}
+	// endregion
+
+	// region: tokenize_owned_03
+	#[test]
+	fn tokenize_owned_03() {
+		let mut calls: usize = 0;
+		let tokens: Vec<String> = tokenize_owned("aBcD", |c: char| {
+			calls += 1;
+			c.is_uppercase()
+		});
+
+		assert_eq!(tokens, ["B", "D"]);
+		assert!(calls >= 4);
+	}
+	// endregion
 }